@@ -0,0 +1,160 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::Part;
+
+/// Known answers of a day, read from `dayN.toml` in [`answers_dir`]:
+///
+/// ```toml
+/// part1 = 1234
+/// part2 = "aj,ds,gg"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable(PathBuf, std::io::Error),
+    Malformed(PathBuf, usize, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Unreadable(p, e) => write!(f, "{}: {}", p.display(), e),
+            AnswersError::Malformed(p, line, msg) => {
+                write!(f, "{}:{}: {}", p.display(), line, msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, (usize, String)> {
+        let mut answers = Self::default();
+
+        for (ln, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| (ln + 1, format!("expected `key = value`, found `{line}`")))?;
+
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| (ln + 1, format!("unterminated string `{value}`")))?,
+                None => value,
+            };
+
+            match key.trim() {
+                "part1" => answers.part1 = Some(value.to_string()),
+                "part2" => answers.part2 = Some(value.to_string()),
+                key => return Err((ln + 1, format!("unknown key `{key}`"))),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, actual: &str) -> Check {
+        match self.get(part) {
+            None => Check::Missing,
+            Some(expected) if expected == actual => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+/// Where the answers to the days' own inputs are kept: beside the inputs in
+/// `$AOC_INPUT_DIR` if set, so that they change along with them, otherwise
+/// the `answers` directory of this crate.
+pub fn answers_dir() -> PathBuf {
+    match std::env::var_os("AOC_INPUT_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("answers"),
+    }
+}
+
+pub fn answers_path(day: usize) -> PathBuf {
+    answers_dir().join(format!("day{day}.toml"))
+}
+
+/// A day without an answers file simply has no known answers yet.
+pub fn load_answers(day: usize) -> Result<Answers, AnswersError> {
+    load_answers_file(&answers_path(day))
+}
+
+pub fn load_answers_file(path: &Path) -> Result<Answers, AnswersError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(e) => return Err(AnswersError::Unreadable(path.to_path_buf(), e)),
+    };
+
+    Answers::parse(&content)
+        .map_err(|(line, msg)| AnswersError::Malformed(path.to_path_buf(), line, msg))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            "# day 23
+part1 = 1154
+part2 = \"aj,ds,gg\"
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("1154"));
+        assert_eq!(answers.get(Part::Two), Some("aj,ds,gg"));
+        assert_eq!(answers.check(Part::One, "1154"), Check::Pass);
+        assert_eq!(
+            answers.check(Part::Two, "aj"),
+            Check::Fail {
+                expected: "aj,ds,gg".to_string(),
+                actual: "aj".to_string()
+            }
+        );
+        assert_eq!(Answers::default().check(Part::One, "1"), Check::Missing);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Answers::parse("part1 1").unwrap_err().0, 1);
+        assert_eq!(Answers::parse("\npart3 = 1").unwrap_err().0, 2);
+        assert_eq!(Answers::parse("part2 = \"1").unwrap_err().0, 1);
+    }
+}
@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+use crate::{error::ParseError, json::Json, solution::Parser, Part};
+
+/// Median timings of one day over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub day: usize,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("day".to_string(), self.day.into()),
+            (
+                "parse_ns".to_string(),
+                (self.parse.as_nanos() as f64).into(),
+            ),
+            (
+                "part1_ns".to_string(),
+                (self.part1.as_nanos() as f64).into(),
+            ),
+            (
+                "part2_ns".to_string(),
+                (self.part2.as_nanos() as f64).into(),
+            ),
+        ])
+    }
+
+    pub fn from_json(json: &Json) -> Option<Self> {
+        let ns = |key| {
+            json.get(key)
+                .and_then(Json::as_f64)
+                .map(|n| Duration::from_nanos(n as u64))
+        };
+
+        Some(Self {
+            day: json.get("day")?.as_f64()? as usize,
+            parse: ns("parse_ns")?,
+            part1: ns("part1_ns")?,
+            part2: ns("part2_ns")?,
+        })
+    }
+}
+
+pub fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+
+    match samples.len() {
+        0 => Duration::ZERO,
+        n if n % 2 == 1 => samples[n / 2],
+        n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+    }
+}
+
+/// Parses and solves `input` `runs` times, from scratch each time.
+pub fn bench(day: usize, parser: Parser, input: &str, runs: usize) -> Result<Timing, ParseError> {
+    let (mut parse, mut part1, mut part2) = (vec![], vec![], vec![]);
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let solution = parser(input)?;
+        parse.push(start.elapsed());
+
+        for (part, samples) in [(Part::One, &mut part1), (Part::Two, &mut part2)] {
+            let start = Instant::now();
+            std::hint::black_box(solution.part(part));
+            samples.push(start.elapsed());
+        }
+    }
+
+    Ok(Timing {
+        day,
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+pub fn baseline_to_json(timings: &[Timing]) -> Json {
+    Json::Object(vec![(
+        "days".to_string(),
+        Json::Array(timings.iter().map(Timing::to_json).collect()),
+    )])
+}
+
+pub fn baseline_from_json(json: &Json) -> Result<Vec<Timing>, String> {
+    json.get("days")
+        .and_then(Json::as_array)
+        .ok_or("baseline has no `days` array")?
+        .iter()
+        .map(|t| Timing::from_json(t).ok_or_else(|| format!("malformed timing: {t}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn medians() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(ms(2), median(vec![ms(3), ms(1), ms(2)]));
+        assert_eq!(ms(3), median(vec![ms(4), ms(1), ms(2), ms(9)]));
+        assert_eq!(Duration::ZERO, median(vec![]));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let timings = vec![Timing {
+            day: 3,
+            parse: Duration::from_nanos(1200),
+            part1: Duration::from_micros(45),
+            part2: Duration::from_millis(6),
+        }];
+
+        let json = Json::parse(&baseline_to_json(&timings).to_string()).unwrap();
+        assert_eq!(Ok(timings), baseline_from_json(&json));
+    }
+}
@@ -0,0 +1,821 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    process::ExitCode,
+    sync::mpsc::{self, Receiver},
+    time::{Duration, Instant},
+};
+
+use aoc_2024::{
+    answers::{load_answers_file, Check},
+    bench::{self, Timing},
+    days, gen, image,
+    input::{self, input_path, read_input_file, read_input_stdin, InputError},
+    json::Json,
+    read_input,
+    viz::{self, Animation},
+    ParseError, Part,
+};
+
+static USAGE: &str = "usage:
+    aoc run [<day>|all] [--part 1|2] [--input <path>|-] [--input-name <name>] [--json]
+    aoc run 1 --external <chunk> [--part 1|2] [--input <path>|-]
+    aoc verify [<day>|all]
+    aoc bench [<day>|all] [--runs <n>] [--sort day|parse|part1|part2|total]
+              [--save <path>] [--compare <path>]
+    aoc gen <day> [--seed <n>] [--size <m>]
+    aoc viz <day> [--input <path>] [--frame <n>] [--plain] [--gif <path> [--count <n>]]
+    aoc image <day> <path.png|path.ppm> [--input <path>|-]
+    aoc new <day>";
+
+fn parse_days(arg: &str) -> Result<Vec<usize>, String> {
+    if arg == "all" {
+        return Ok(all_days());
+    }
+
+    arg.parse::<usize>()
+        .ok()
+        .filter(|d| days::get(*d).is_some())
+        .map(|d| vec![d])
+        .ok_or_else(|| format!("unknown day: {arg}"))
+}
+
+fn all_days() -> Vec<usize> {
+    days::REGISTRY.iter().map(|&(day, _)| day).collect()
+}
+
+fn part_number(part: Part) -> usize {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+struct RunArgs {
+    days: Vec<usize>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    input_name: Option<String>,
+    json: bool,
+    /// Day 1 from disk, with at most this many entries of a list in memory.
+    external: Option<usize>,
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = None;
+        let mut parts = vec![Part::One, Part::Two];
+        let mut input = None;
+        let mut input_name = None;
+        let mut json = false;
+        let mut external = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => json = true,
+                "--external" => {
+                    let chunk = args.next().ok_or("missing size after --external")?;
+                    external = Some(
+                        chunk
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or_else(|| format!("invalid chunk size: {chunk}"))?,
+                    );
+                }
+                "--part" | "-p" => {
+                    parts = match args.next().as_deref() {
+                        Some("1") => vec![Part::One],
+                        Some("2") => vec![Part::Two],
+                        other => return Err(format!("invalid part: {other:?}")),
+                    }
+                }
+                "--input" | "-i" => {
+                    input = Some(args.next().ok_or("missing path after --input")?);
+                }
+                "--input-name" => {
+                    input_name = Some(args.next().ok_or("missing name after --input-name")?);
+                }
+                day if days.is_none() => days = Some(parse_days(day)?),
+                other => return Err(format!("unexpected argument: {other}")),
+            }
+        }
+
+        let days = days.unwrap_or_else(all_days);
+
+        if input.is_some() && days.len() > 1 {
+            return Err("--input needs a single day".to_string());
+        }
+        if input.is_some() && input_name.is_some() {
+            return Err("--input and --input-name do not go together".to_string());
+        }
+        if external.is_some() && days != [1] {
+            return Err("--external is only for day 1".to_string());
+        }
+        if external.is_some() && (json || input_name.is_some()) {
+            return Err("--external does not go with --json or --input-name".to_string());
+        }
+
+        Ok(Self {
+            days,
+            parts,
+            input,
+            input_name,
+            json,
+            external,
+        })
+    }
+}
+
+/// The error with its day, followed by the offending line of `input`.
+fn parse_failure(day: usize, input: &str, err: ParseError) -> String {
+    let snippet = err.snippet(input);
+    format!("{}\n{snippet}", err.with_day(day))
+}
+
+/// The input at `path`, `-` for stdin, or else the day's own, with where it
+/// came from.
+fn load_input(day: usize, path: Option<&str>) -> Result<(String, String), String> {
+    let (input, path) = match path {
+        Some("-") => (read_input_stdin(), "<stdin>".to_string()),
+        Some(path) => (read_input_file(Path::new(path)), path.to_string()),
+        None => (read_input(day), input_path(day).display().to_string()),
+    };
+    Ok((input.map_err(|e| e.to_string())?, path))
+}
+
+/// The day's input called `name`, with where it came from.
+fn load_fixture(day: usize, name: &str) -> Result<(String, String), String> {
+    let Some(fixture) = input::fixture(day, name) else {
+        let names = input::fixtures(day)
+            .into_iter()
+            .map(|f| f.name)
+            .collect::<Vec<_>>();
+        return Err(format!(
+            "day {day} has no input named {name}; it has {}",
+            names.join(", ")
+        ));
+    };
+
+    let input = fixture.read().map_err(|e| e.to_string())?;
+    Ok((input, fixture.input.display().to_string()))
+}
+
+/// Day 1 streamed from its input through sorted runs on disk, for lists
+/// too long to hold in memory.
+fn run_external(args: &RunArgs, chunk: usize) -> Result<(), String> {
+    let path = match args.input.as_deref() {
+        Some(path) => path.to_string(),
+        None => input_path(1).display().to_string(),
+    };
+    let dir = std::env::temp_dir();
+
+    let start = Instant::now();
+    let answers = if path == "-" {
+        days::day1::external::compare(std::io::stdin().lock(), chunk, &dir)
+    } else {
+        std::fs::File::open(&path)
+            .and_then(|file| days::day1::external::compare(BufReader::new(file), chunk, &dir))
+    }
+    .map_err(|e| format!("{path}: {e}"))?;
+    let elapsed = start.elapsed();
+
+    println!("Day 1 (from disk, {chunk} entries at a time, in {elapsed:.2?})");
+    for &part in args.parts.iter() {
+        let answer = match part {
+            Part::One => answers.0,
+            Part::Two => answers.1,
+        };
+        println!("  Part {}: {answer}", part_number(part));
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if let Some(chunk) = args.external {
+        return run_external(&args, chunk);
+    }
+
+    for &day in args.days.iter() {
+        let parser = days::get(day).ok_or_else(|| format!("unknown day: {day}"))?;
+        let (input, path) = match args.input_name.as_deref() {
+            Some(name) => load_fixture(day, name)?,
+            None => load_input(day, args.input.as_deref())?,
+        };
+
+        let start = Instant::now();
+        let solution = parser(&input).map_err(|e| parse_failure(day, &input, e))?;
+        let parse_time = start.elapsed();
+
+        if !args.json {
+            println!("Day {day} (parsed in {parse_time:.2?})");
+        }
+
+        for &part in args.parts.iter() {
+            let start = Instant::now();
+            let answer = solution.part(part);
+            let elapsed = start.elapsed();
+
+            let n = part_number(part);
+            if args.json {
+                let record = Json::Object(vec![
+                    ("day".to_string(), day.into()),
+                    ("part".to_string(), n.into()),
+                    ("answer".to_string(), answer.into()),
+                    (
+                        "parse_ns".to_string(),
+                        (parse_time.as_nanos() as f64).into(),
+                    ),
+                    ("solve_ns".to_string(), (elapsed.as_nanos() as f64).into()),
+                    ("input".to_string(), path.as_str().into()),
+                ]);
+                println!("{record}");
+            } else {
+                println!("  Part {n}: {answer:<20} ({elapsed:.2?})");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct VerifyArgs {
+    days: Vec<usize>,
+}
+
+impl VerifyArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let days = match args.next() {
+            Some(day) => parse_days(&day)?,
+            None => all_days(),
+        };
+
+        if let Some(other) = args.next() {
+            return Err(format!("unexpected argument: {other}"));
+        }
+
+        Ok(Self { days })
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (day, fixture) in args
+        .days
+        .iter()
+        .flat_map(|&day| input::fixtures(day).into_iter().map(move |f| (day, f)))
+    {
+        let parser = days::get(day).ok_or_else(|| format!("unknown day: {day}"))?;
+        let answers = load_answers_file(&fixture.answers).map_err(|e| e.to_string())?;
+        let label = format!("Day {day:<3} {:<10}", fixture.name);
+
+        let input = match fixture.read() {
+            Ok(input) => input,
+            // a day yet to be filled in, as `aoc new` leaves it
+            Err(InputError::Missing(_) | InputError::Empty(_))
+                if fixture.name == input::DEFAULT_FIXTURE =>
+            {
+                println!("{label} part 1: missing    part 2: missing");
+                missing += 2;
+                continue;
+            }
+            Err(e) => {
+                println!("{label} error: {e}");
+                failed += 2;
+                continue;
+            }
+        };
+
+        let solution = match parser(&input) {
+            Ok(solution) => solution,
+            Err(e) => {
+                println!("{label} error: {}", parse_failure(day, &input, e));
+                failed += 2;
+                continue;
+            }
+        };
+        let mut line = label;
+
+        for part in [Part::One, Part::Two] {
+            let n = part_number(part);
+            let status = match answers.check(part, &solution.part(part)) {
+                Check::Pass => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                Check::Missing => {
+                    missing += 1;
+                    "missing".to_string()
+                }
+                Check::Fail { expected, actual } => {
+                    failed += 1;
+                    format!("FAIL (expected {expected}, got {actual})")
+                }
+            };
+
+            line.push_str(&format!(" part {n}: {status:<10}"));
+        }
+
+        println!("{}", line.trim_end());
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        return Err(format!("{failed} answer(s) did not match"));
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+enum SortKey {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+struct BenchArgs {
+    days: Vec<usize>,
+    runs: usize,
+    sort: SortKey,
+    save: Option<String>,
+    compare: Option<String>,
+}
+
+impl BenchArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = None;
+        let mut runs = 5;
+        let mut sort = SortKey::Day;
+        let mut save = None;
+        let mut compare = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--runs" | "-n" => {
+                    runs = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or("--runs needs a positive number")?;
+                }
+                "--sort" | "-s" => {
+                    sort = match args.next().as_deref() {
+                        Some("day") => SortKey::Day,
+                        Some("parse") => SortKey::Parse,
+                        Some("part1") => SortKey::Part1,
+                        Some("part2") => SortKey::Part2,
+                        Some("total") => SortKey::Total,
+                        other => return Err(format!("invalid sort key: {other:?}")),
+                    }
+                }
+                "--save" => save = Some(args.next().ok_or("missing path after --save")?),
+                "--compare" => {
+                    compare = Some(args.next().ok_or("missing path after --compare")?);
+                }
+                day if days.is_none() => days = Some(parse_days(day)?),
+                other => return Err(format!("unexpected argument: {other}")),
+            }
+        }
+
+        Ok(Self {
+            days: days.unwrap_or_else(all_days),
+            runs,
+            sort,
+            save,
+            compare,
+        })
+    }
+}
+
+fn load_baseline(path: &str) -> Result<Vec<Timing>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    let json = Json::parse(&content).map_err(|e| format!("{path}: {e}"))?;
+    bench::baseline_from_json(&json).map_err(|e| format!("{path}: {e}"))
+}
+
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = args.compare.as_deref().map(load_baseline).transpose()?;
+
+    let mut timings = vec![];
+    for &day in args.days.iter() {
+        let parser = days::get(day).ok_or_else(|| format!("unknown day: {day}"))?;
+        let input = read_input(day).map_err(|e| e.to_string())?;
+
+        eprint!("\rbenchmarking day {day}...");
+        let timing = bench::bench(day, parser, &input, args.runs)
+            .map_err(|e| parse_failure(day, &input, e))?;
+        timings.push(timing);
+    }
+    eprint!("\r{:30}\r", "");
+
+    if let Some(path) = args.save.as_deref() {
+        let json = bench::baseline_to_json(&timings);
+        std::fs::write(path, format!("{json}\n")).map_err(|e| format!("{path}: {e}"))?;
+    }
+
+    let mut rows = timings.clone();
+    match args.sort {
+        SortKey::Day => rows.sort_by_key(|t| t.day),
+        SortKey::Parse => rows.sort_by_key(|t| std::cmp::Reverse(t.parse)),
+        SortKey::Part1 => rows.sort_by_key(|t| std::cmp::Reverse(t.part1)),
+        SortKey::Part2 => rows.sort_by_key(|t| std::cmp::Reverse(t.part2)),
+        SortKey::Total => rows.sort_by_key(|t| std::cmp::Reverse(t.total())),
+    }
+
+    print!(
+        "{:>3} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    if baseline.is_some() {
+        print!(" {:>9}", "vs base");
+    }
+    println!();
+
+    for t in rows.iter() {
+        print!(
+            "{:>3} {:>12} {:>12} {:>12} {:>12}",
+            t.day,
+            format!("{:.2?}", t.parse),
+            format!("{:.2?}", t.part1),
+            format!("{:.2?}", t.part2),
+            format!("{:.2?}", t.total()),
+        );
+
+        if let Some(baseline) = baseline.as_ref() {
+            match baseline.iter().find(|b| b.day == t.day) {
+                Some(b) => {
+                    let change =
+                        (t.total().as_secs_f64() / b.total().as_secs_f64().max(1e-9) - 1.0) * 100.0;
+                    print!(" {:>+8.1}%", change);
+                }
+                None => print!(" {:>9}", "-"),
+            }
+        }
+        println!();
+    }
+
+    let total = timings
+        .iter()
+        .map(Timing::total)
+        .sum::<std::time::Duration>();
+    println!("{:>3} {:>51}", "", format!("{total:.2?}"));
+
+    Ok(())
+}
+
+struct GenArgs {
+    day: usize,
+    seed: u64,
+    size: Option<usize>,
+}
+
+impl GenArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut seed = 0;
+        let mut size = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    seed = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .ok_or("--seed needs a number")?;
+                }
+                "--size" => {
+                    size = Some(
+                        args.next()
+                            .and_then(|n| n.parse().ok())
+                            .ok_or("--size needs a number")?,
+                    );
+                }
+                d if day.is_none() => {
+                    day = Some(
+                        d.parse()
+                            .ok()
+                            .filter(|&d| gen::get(d).is_some())
+                            .ok_or_else(|| format!("unknown day: {d}"))?,
+                    );
+                }
+                other => return Err(format!("unexpected argument: {other}")),
+            }
+        }
+
+        Ok(Self {
+            day: day.ok_or("missing day")?,
+            seed,
+            size,
+        })
+    }
+}
+
+fn run_gen(args: GenArgs) -> Result<(), String> {
+    let input = gen::generate(args.day, args.seed, args.size)
+        .ok_or_else(|| format!("no generator for day {}", args.day))?;
+    print!("{input}");
+    Ok(())
+}
+
+struct VizArgs {
+    day: usize,
+    input: Option<String>,
+    frame: Option<usize>,
+    plain: bool,
+    gif: Option<String>,
+    count: usize,
+}
+
+impl VizArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut input = None;
+        let mut frame = None;
+        let mut plain = false;
+        let mut gif = None;
+        let mut count = 100;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    input = Some(args.next().ok_or("missing path after --input")?);
+                }
+                "--gif" => gif = Some(args.next().ok_or("missing path after --gif")?),
+                "--count" => {
+                    count = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or("--count needs a positive number")?;
+                }
+                "--frame" => {
+                    frame = Some(
+                        args.next()
+                            .and_then(|n| n.parse().ok())
+                            .ok_or("--frame needs a number")?,
+                    );
+                }
+                "--plain" => plain = true,
+                d if day.is_none() => {
+                    day = Some(
+                        d.parse()
+                            .ok()
+                            .filter(|&d| viz::get(d).is_some())
+                            .ok_or_else(|| format!("no visualisation for day {d}"))?,
+                    );
+                }
+                other => return Err(format!("unexpected argument: {other}")),
+            }
+        }
+
+        if input.as_deref() == Some("-") && frame.is_none() && gif.is_none() {
+            return Err("stdin is for commands; pass --frame to read input from it".to_string());
+        }
+
+        Ok(Self {
+            day: day.ok_or("missing day")?,
+            input,
+            frame,
+            plain,
+            gif,
+            count,
+        })
+    }
+}
+
+static VIZ_HELP: &str =
+    "[enter] next  n <k> forward  b [k] back  g <k> go to  p [fps] play, [enter] pauses  q quit";
+
+/// Clears the terminal and shows frame `i` with its caption.
+fn draw(animation: &mut dyn Animation, i: usize, colour: bool, note: &str) {
+    let frame = animation.frame(i);
+    let mut out = String::new();
+    if colour {
+        out.push_str("\x1b[H\x1b[2J");
+    }
+    out.push_str(&frame.render(colour));
+    out.push_str(&format!(
+        "frame {i} of {}: {}\n{VIZ_HELP}\n",
+        animation.frames() - 1,
+        frame.caption
+    ));
+    if !note.is_empty() {
+        out.push_str(&format!("{note}\n"));
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    // a closed terminal is the user's way of quitting
+    let _ = stdout
+        .write_all(out.as_bytes())
+        .and_then(|_| stdout.flush());
+}
+
+/// Lines typed on stdin, read on their own thread so that playing can be
+/// paused.
+fn commands() -> Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+fn run_viz(args: VizArgs) -> Result<(), String> {
+    let animator = viz::get(args.day).ok_or_else(|| format!("unknown day: {}", args.day))?;
+    let (input, _) = load_input(args.day, args.input.as_deref())?;
+    let mut animation = animator(&input).map_err(|e| parse_failure(args.day, &input, e))?;
+    let last = animation.frames() - 1;
+    let colour = !args.plain;
+
+    if let Some(path) = args.gif.as_deref() {
+        let from = args.frame.unwrap_or(0).min(last);
+        let to = (from + args.count).min(last + 1);
+        let frames = (from..to)
+            .map(|i| animation.frame(i).image(4))
+            .collect::<Vec<_>>();
+        return image::save_gif(&frames, 10, Path::new(path)).map_err(|e| format!("{path}: {e}"));
+    }
+
+    if let Some(i) = args.frame {
+        if i > last {
+            return Err(format!("frame {i} is past the last, {last}"));
+        }
+        let frame = animation.frame(i);
+        println!("{}{}", frame.render(colour), frame.caption);
+        return Ok(());
+    }
+
+    let rx = commands();
+    let mut at = 0;
+    let mut note = String::new();
+
+    loop {
+        draw(animation.as_mut(), at, colour, &note);
+        note.clear();
+
+        let Ok(line) = rx.recv() else {
+            return Ok(());
+        };
+        let mut words = line.split_whitespace();
+        let cmd = words.next().unwrap_or("n");
+        let count = match words.next().map(str::parse::<usize>) {
+            None => None,
+            Some(Ok(k)) => Some(k),
+            Some(Err(_)) => {
+                note = format!("not a number in `{line}`");
+                continue;
+            }
+        };
+
+        match cmd {
+            "n" => at = (at + count.unwrap_or(1)).min(last),
+            "b" => at = at.saturating_sub(count.unwrap_or(1)),
+            "g" => match count {
+                Some(k) => at = k.min(last),
+                None => note = "g needs a frame number".to_string(),
+            },
+            "p" => {
+                let fps = count.unwrap_or(10).max(1) as u64;
+                while at < last {
+                    if rx.try_recv().is_ok() {
+                        break;
+                    }
+                    std::thread::sleep(Duration::from_millis(1000 / fps));
+                    at += 1;
+                    draw(animation.as_mut(), at, colour, "playing, [enter] pauses");
+                }
+            }
+            "q" => return Ok(()),
+            other => note = format!("unknown command `{other}`"),
+        }
+    }
+}
+
+struct ImageArgs {
+    day: usize,
+    path: String,
+    input: Option<String>,
+}
+
+impl ImageArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut path = None;
+        let mut input = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    input = Some(args.next().ok_or("missing path after --input")?);
+                }
+                d if day.is_none() => {
+                    day = Some(
+                        d.parse()
+                            .ok()
+                            .filter(|&d| image::get(d).is_some())
+                            .ok_or_else(|| format!("no picture for day {d}"))?,
+                    );
+                }
+                p if path.is_none() => path = Some(p.to_string()),
+                other => return Err(format!("unexpected argument: {other}")),
+            }
+        }
+
+        Ok(Self {
+            day: day.ok_or("missing day")?,
+            path: path.ok_or("missing output path")?,
+            input,
+        })
+    }
+}
+
+fn run_image(args: ImageArgs) -> Result<(), String> {
+    let painter = image::get(args.day).ok_or_else(|| format!("unknown day: {}", args.day))?;
+    let (input, _) = load_input(args.day, args.input.as_deref())?;
+    let picture = painter(&input).map_err(|e| parse_failure(args.day, &input, e))?;
+    picture
+        .save(Path::new(&args.path))
+        .map_err(|e| e.to_string())
+}
+
+fn run_new(day: usize) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let created = aoc_2024::scaffold::new_day(root, day).map_err(|e| e.to_string())?;
+    for path in created {
+        println!("created {}", path.display());
+    }
+    println!("registered day {day} in src/days/mod.rs");
+    Ok(())
+}
+
+enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Gen(GenArgs),
+    Viz(VizArgs),
+    Image(ImageArgs),
+    New(usize),
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            Some("run") => RunArgs::parse(args).map(Command::Run),
+            Some("verify") => VerifyArgs::parse(args).map(Command::Verify),
+            Some("bench") => BenchArgs::parse(args).map(Command::Bench),
+            Some("gen") => GenArgs::parse(args).map(Command::Gen),
+            Some("viz") => VizArgs::parse(args).map(Command::Viz),
+            Some("image") => ImageArgs::parse(args).map(Command::Image),
+            Some("new") => match (args.next(), args.next()) {
+                (Some(day), None) => day
+                    .parse()
+                    .map(Command::New)
+                    .map_err(|_| format!("invalid day: {day}")),
+                (None, _) => Err("missing day".to_string()),
+                (_, Some(other)) => Err(format!("unexpected argument: {other}")),
+            },
+            Some(cmd) => Err(format!("unknown command: {cmd}")),
+            None => Err("missing command".to_string()),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cmd = match Command::parse(std::env::args().skip(1)) {
+        Ok(cmd) => cmd,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let res = match cmd {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => run_bench(args),
+        Command::Gen(args) => run_gen(args),
+        Command::Viz(args) => run_viz(args),
+        Command::Image(args) => run_image(args),
+        Command::New(day) => run_new(day),
+    };
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,547 @@
+use std::{collections::BinaryHeap, fmt::Write};
+
+use itertools::{EitherOrBoth, Itertools};
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::{map, opt},
+    sequence::{pair, preceded},
+};
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    parse::{self, lines, lit, spaced, unsigned, PResult},
+    Solution,
+};
+
+/// Sum of the gaps between the sorted lists paired off in order. Entries
+/// past the end of the shorter list have no partner and add nothing.
+fn distance(
+    left: impl IntoIterator<Item = usize>,
+    right: impl IntoIterator<Item = usize>,
+) -> usize {
+    left.into_iter()
+        .zip(right)
+        .map(|(l, r)| l.abs_diff(r))
+        .sum()
+}
+
+/// Sum of each left entry times how often it appears on the right, for
+/// sorted lists of any lengths.
+fn similarity(
+    left: impl IntoIterator<Item = usize>,
+    right: impl IntoIterator<Item = usize>,
+) -> usize {
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    let mut sum = 0;
+
+    while let Some(cur) = left.next() {
+        let mut ln = 1;
+        while left.next_if_eq(&cur).is_some() {
+            ln += 1;
+        }
+
+        while right.next_if(|&r| r < cur).is_some() {}
+        let mut rn = 0;
+        while right.next_if_eq(&cur).is_some() {
+            rn += 1;
+        }
+
+        sum += cur * ln * rn;
+    }
+
+    sum
+}
+
+/// How often each value appears in a sorted list, smallest first.
+fn counts(sorted: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    sorted.iter().dedup_with_count().map(|(n, &v)| (v, n))
+}
+
+/// The counts of the values in either list, side by side.
+fn both<'a>(
+    a: &'a [usize],
+    b: &'a [usize],
+) -> impl Iterator<Item = EitherOrBoth<(usize, usize)>> + 'a {
+    counts(a).merge_join_by(counts(b), |x, y| x.0.cmp(&y.0))
+}
+
+/// A way of comparing two sorted lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the gaps between entries paired off in order, as in part 1.
+    Distance,
+    /// Each entry of the first list times how often it is in the second, as
+    /// in part 2.
+    Similarity,
+    /// Distinct values in both over distinct values in either.
+    Jaccard,
+    /// Entries the lists have in common, counting repeats.
+    Intersection,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [
+        Metric::Distance,
+        Metric::Similarity,
+        Metric::Jaccard,
+        Metric::Intersection,
+    ];
+
+    /// `a` against `b`. Two empty lists have a Jaccard overlap of 1.
+    pub fn compare(self, a: &[usize], b: &[usize]) -> f64 {
+        match self {
+            Metric::Distance => distance(a.iter().copied(), b.iter().copied()) as f64,
+            Metric::Similarity => similarity(a.iter().copied(), b.iter().copied()) as f64,
+            Metric::Jaccard => {
+                let (shared, all) = both(a, b).fold((0, 0), |(shared, all), e| {
+                    (shared + usize::from(e.is_both()), all + 1)
+                });
+                if all == 0 {
+                    1.0
+                } else {
+                    shared as f64 / all as f64
+                }
+            }
+            Metric::Intersection => both(a, b)
+                .filter_map(|e| e.both().map(|((_, m), (_, n))| m.min(n)))
+                .sum::<usize>() as f64,
+        }
+    }
+}
+
+/// Any number of lists side by side, each sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns(pub Vec<Vec<usize>>);
+
+impl Columns {
+    /// Rows of entries separated by spaces, with `-` where a column has run
+    /// out. Trailing `-`s may be left off.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rows = parse::finish(input, lines(spaced(cell)))?;
+        let n = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut columns = vec![vec![]; n];
+        for row in rows {
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.extend(value);
+            }
+        }
+        columns.iter_mut().for_each(|c| c.sort_unstable());
+
+        Ok(Self(columns))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Column `a` against column `b`, if both exist.
+    pub fn compare(&self, a: usize, b: usize, metric: Metric) -> Option<f64> {
+        Some(metric.compare(self.0.get(a)?, self.0.get(b)?))
+    }
+
+    /// Every column against every other, with row `a`, column `b` holding
+    /// `a` against `b`.
+    pub fn matrix(&self, metric: Metric) -> Grid<f64> {
+        Grid::from_rows(
+            self.0
+                .iter()
+                .map(|a| self.0.iter().map(|b| metric.compare(a, b)).collect())
+                .collect(),
+        )
+    }
+
+    /// The matrix for each of `metrics` as a table, columns numbered from 1.
+    pub fn report(&self, metrics: &[Metric]) -> String {
+        let mut out = String::new();
+        for &metric in metrics {
+            let matrix = self.matrix(metric);
+            let cells = matrix.map(|&v| {
+                if v.fract() == 0.0 {
+                    format!("{v}")
+                } else {
+                    format!("{v:.3}")
+                }
+            });
+            let width = cells.iter().map(|(_, c)| c.len()).max().unwrap_or(0).max(2);
+
+            writeln!(out, "{metric:?}").unwrap();
+            write!(out, "   ").unwrap();
+            for b in 1..=self.len() {
+                write!(out, " {b:>width$}").unwrap();
+            }
+            out.push('\n');
+            for (a, row) in cells.rows().enumerate() {
+                write!(out, "{:>3}", a + 1).unwrap();
+                for cell in row {
+                    write!(out, " {cell:>width$}").unwrap();
+                }
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+/// One entry of a list, or `-` where that list has run out.
+fn cell(input: &str) -> PResult<'_, Option<usize>> {
+    alt((map(unsigned, Some), map(lit("-"), |_| None)))(input)
+}
+
+/// A left and a right entry. The right one may be left off when the right
+/// list is the shorter.
+fn row(input: &str) -> PResult<'_, (Option<usize>, Option<usize>)> {
+    map(pair(cell, opt(preceded(space1, cell))), |(l, r)| {
+        (l, r.flatten())
+    })(input)
+}
+
+fn parse(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let rows = parse::finish(input, lines(row))?;
+    let left = rows.iter().filter_map(|r| r.0).collect::<BinaryHeap<_>>();
+    let right = rows.iter().filter_map(|r| r.1).collect::<BinaryHeap<_>>();
+
+    Ok((left.into_sorted_vec(), right.into_sorted_vec()))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((left, right): &Self::Input) -> usize {
+        distance(left.iter().copied(), right.iter().copied())
+    }
+
+    fn part2((left, right): &Self::Input) -> usize {
+        similarity(left.iter().copied(), right.iter().copied())
+    }
+}
+
+/// Both answers for input read a line at a time, for lists too long to hold
+/// in memory. Each list is sorted in runs spilled to temporary files, which
+/// are merged back for the answers and removed when done. `aoc run 1
+/// --external <chunk>` answers this way.
+pub mod external {
+    use std::{
+        cmp::Reverse,
+        collections::BinaryHeap,
+        fs::File,
+        io::{self, BufRead, BufReader, BufWriter, Read, Write},
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use crate::parse;
+
+    /// Tells apart the runs of comparisons going on at once.
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+    /// The most runs read at once. Past that, runs are merged a group at a
+    /// time into longer ones first.
+    const FAN_IN: usize = 16;
+
+    /// A sorted run on disk, removed when dropped.
+    struct Run(PathBuf);
+
+    impl Drop for Run {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    /// One list, kept in memory up to `chunk` entries at a time.
+    struct Spill<'a> {
+        dir: &'a Path,
+        name: String,
+        chunk: usize,
+        buffer: Vec<usize>,
+        runs: Vec<Run>,
+        /// Runs written so far, to name the next one.
+        written: usize,
+    }
+
+    impl<'a> Spill<'a> {
+        fn new(dir: &'a Path, name: String, chunk: usize) -> Self {
+            Self {
+                dir,
+                name,
+                chunk: chunk.max(1),
+                buffer: vec![],
+                runs: vec![],
+                written: 0,
+            }
+        }
+
+        fn push(&mut self, value: usize) -> io::Result<()> {
+            self.buffer.push(value);
+            if self.buffer.len() >= self.chunk {
+                self.flush()?;
+            }
+            Ok(())
+        }
+
+        /// Sorts what is buffered and writes it out as a run.
+        fn flush(&mut self) -> io::Result<()> {
+            if self.buffer.is_empty() {
+                return Ok(());
+            }
+            self.buffer.sort_unstable();
+
+            let buffer = std::mem::take(&mut self.buffer);
+            self.write(buffer)
+        }
+
+        /// Writes `values`, which must be in order, out as a new run.
+        fn write(&mut self, values: impl IntoIterator<Item = usize>) -> io::Result<()> {
+            let path = self.dir.join(format!("{}-{}.run", self.name, self.written));
+            self.written += 1;
+
+            let mut out = BufWriter::new(File::create(&path)?);
+            self.runs.push(Run(path));
+            for value in values {
+                out.write_all(&(value as u64).to_le_bytes())?;
+            }
+            out.flush()
+        }
+
+        /// Merges the oldest runs `FAN_IN` at a time until no more than that
+        /// are left, so that no merge opens more files than that.
+        fn compact(&mut self) -> io::Result<()> {
+            while self.runs.len() > FAN_IN {
+                let group = self.runs.drain(..FAN_IN).collect::<Vec<_>>();
+                let mut merge = Merge::open(&group)?;
+                self.write(merge.by_ref())?;
+                merge.finish()?;
+            }
+            Ok(())
+        }
+
+        /// The whole list in order, read back from the runs.
+        fn merged(&self) -> io::Result<Merge> {
+            Merge::open(&self.runs)
+        }
+    }
+
+    fn read(reader: &mut impl Read) -> io::Result<Option<usize>> {
+        let mut bytes = [0; 8];
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => Ok(Some(u64::from_le_bytes(bytes) as usize)),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sorted runs merged into one sorted stream. A read error ends the
+    /// stream early and is kept for [`Merge::finish`].
+    struct Merge {
+        readers: Vec<BufReader<File>>,
+        heap: BinaryHeap<Reverse<(usize, usize)>>,
+        error: Option<io::Error>,
+    }
+
+    impl Merge {
+        fn open(runs: &[Run]) -> io::Result<Self> {
+            let mut merge = Merge {
+                readers: vec![],
+                heap: BinaryHeap::new(),
+                error: None,
+            };
+            for (i, run) in runs.iter().enumerate() {
+                let mut reader = BufReader::new(File::open(&run.0)?);
+                if let Some(value) = read(&mut reader)? {
+                    merge.heap.push(Reverse((value, i)));
+                }
+                merge.readers.push(reader);
+            }
+            Ok(merge)
+        }
+
+        fn finish(self) -> io::Result<()> {
+            self.error.map_or(Ok(()), Err)
+        }
+    }
+
+    impl Iterator for Merge {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            let Reverse((value, i)) = self.heap.pop()?;
+            match read(&mut self.readers[i]) {
+                Ok(Some(next)) => self.heap.push(Reverse((next, i))),
+                Ok(None) => {}
+                Err(e) => {
+                    self.error = Some(e);
+                    self.heap.clear();
+                }
+            }
+            Some(value)
+        }
+    }
+
+    /// The total distance and the similarity score of `input`, holding at
+    /// most `chunk` entries of each list in memory. Runs go in `dir`.
+    pub fn compare(input: impl BufRead, chunk: usize, dir: &Path) -> io::Result<(usize, usize)> {
+        let call = format!(
+            "day1-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let mut left = Spill::new(dir, format!("{call}-left"), chunk);
+        let mut right = Spill::new(dir, format!("{call}-right"), chunk);
+
+        for (n, line) in input.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (l, r) = parse::finish(&line, super::row).map_err(|mut e| {
+                e.line = n + 1;
+                io::Error::new(io::ErrorKind::InvalidData, e)
+            })?;
+            if let Some(l) = l {
+                left.push(l)?;
+            }
+            if let Some(r) = r {
+                right.push(r)?;
+            }
+        }
+        left.flush()?;
+        right.flush()?;
+        left.compact()?;
+        right.compact()?;
+
+        let (mut l, mut r) = (left.merged()?, right.merged()?);
+        let distance = super::distance(&mut l, &mut r);
+        l.finish()?;
+        r.finish()?;
+
+        let (mut l, mut r) = (left.merged()?, right.merged()?);
+        let similarity = super::similarity(&mut l, &mut r);
+        l.finish()?;
+        r.finish()?;
+
+        Ok((distance, similarity))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use super::*;
+    use crate::pos::Pos;
+
+    #[test]
+    fn example() {
+        let input = Day1::parse(
+            "3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        )
+        .unwrap();
+
+        assert_eq!(11, Day1::part1(&input));
+        assert_eq!(31, Day1::part2(&input));
+    }
+
+    #[test]
+    fn uneven() {
+        let input = Day1::parse(
+            "3   4
+4   3
+2   -
+1
+-   9
+3   3",
+        )
+        .unwrap();
+
+        assert_eq!((vec![1, 2, 3, 3, 4], vec![3, 3, 4, 9]), input);
+        assert_eq!(2 + 1 + 1 + 6, Day1::part1(&input));
+        assert_eq!(3 * 2 * 2 + 4, Day1::part2(&input));
+    }
+
+    #[test]
+    fn columns() {
+        let columns = Columns::parse(
+            "3   4   3
+4   3   -
+2   5   2
+1   3
+3   9   3
+3   3   1",
+        )
+        .unwrap();
+        assert_eq!(vec![1, 2, 3, 3], columns.0[2]);
+
+        let distance = columns.matrix(Metric::Distance);
+        assert_eq!(Some(&11.0), distance.get(Pos::new(0, 1)));
+        assert_eq!(Some(&0.0), distance.get(Pos::new(2, 2)));
+        assert_eq!(Some(31.0), columns.compare(0, 1, Metric::Similarity));
+        assert_eq!(Some(1.0 / 3.0), columns.compare(0, 1, Metric::Jaccard));
+        assert_eq!(Some(4.0), columns.compare(0, 2, Metric::Intersection));
+        assert_eq!(Some(2.0), columns.compare(1, 2, Metric::Intersection));
+        assert_eq!(None, columns.compare(0, 3, Metric::Distance));
+
+        assert_eq!(
+            "Jaccard
+        1     2     3
+  1     1 0.333 0.750
+  2 0.333     1 0.167
+  3 0.750 0.167     1
+",
+            columns.report(&[Metric::Jaccard])
+        );
+    }
+
+    #[test]
+    fn external_matches() {
+        let dir = std::env::temp_dir();
+        for seed in 0..5 {
+            let mut text = crate::gen::generate(1, seed, Some(100)).unwrap();
+            text += &"-   12345\n".repeat(seed as usize);
+            let input = Day1::parse(&text).unwrap();
+
+            assert_eq!(
+                (Day1::part1(&input), Day1::part2(&input)),
+                external::compare(text.as_bytes(), 7, &dir).unwrap(),
+                "seed {seed}"
+            );
+        }
+
+        // a run per entry, merged down in several passes
+        let text = crate::gen::generate(1, 5, Some(300)).unwrap();
+        let input = Day1::parse(&text).unwrap();
+        assert_eq!(
+            (Day1::part1(&input), Day1::part2(&input)),
+            external::compare(text.as_bytes(), 1, &dir).unwrap()
+        );
+        let prefix = format!("day1-{}-", std::process::id());
+        assert!(!std::fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix)));
+
+        let error = external::compare("1   2\n3   x\n".as_bytes(), 7, &dir).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    }
+}
@@ -0,0 +1,140 @@
+use std::{collections::HashSet, ops::Index};
+
+use crate::{error::ParseError, grid::Grid, pos::Pos, Solution};
+
+pub struct Input {
+    map: Grid<usize>,
+}
+
+impl Index<Pos> for Input {
+    type Output = usize;
+
+    fn index(&self, index: Pos) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::try_parse(input, "a height", |c| c.to_digit(10).map(|d| d as usize))?,
+        })
+    }
+
+    fn get_zeros(&self) -> Vec<Pos> {
+        self.map.find_all(&0).collect()
+    }
+
+    fn walk(&self, zero: Pos) -> HashSet<Pos> {
+        let mut frontier = vec![zero];
+
+        let mut nines = HashSet::new();
+
+        while let Some(pos) = frontier.pop() {
+            if self[pos] == 9 {
+                nines.insert(pos);
+                continue;
+            }
+
+            let neighbours = self.map.neighbours4(pos);
+
+            for n in neighbours {
+                if self[n].saturating_sub(self[pos]) == 1 {
+                    frontier.push(n)
+                }
+            }
+
+            // if zero == Pos((5, 2)) {
+            //     println!("{frontier:?}")
+            // }
+        }
+
+        nines
+    }
+
+    fn walk_with_rating(&self, zero: Pos) -> (HashSet<Pos>, usize) {
+        let mut frontier = vec![zero];
+
+        let mut nines = HashSet::new();
+        let mut nines_but_vec = vec![];
+
+        while let Some(pos) = frontier.pop() {
+            if self[pos] == 9 {
+                nines.insert(pos);
+                nines_but_vec.push(pos);
+                continue;
+            }
+
+            let neighbours = self.map.neighbours4(pos);
+
+            for n in neighbours {
+                if self[n].saturating_sub(self[pos]) == 1 {
+                    frontier.push(n);
+                }
+            }
+        }
+
+        (nines, nines_but_vec.len())
+    }
+
+    fn total_trailheads(&self) -> usize {
+        let zeros = self.get_zeros();
+
+        zeros.iter().fold(0, |acc, cur| {
+            let nines = self.walk(*cur);
+            acc + nines.len()
+        })
+    }
+
+    fn total_rating(&self) -> usize {
+        let zeros = self.get_zeros();
+
+        zeros.iter().fold(0, |acc, cur| {
+            let nines = self.walk_with_rating(*cur);
+            acc + nines.1
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.total_trailheads()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.total_rating()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day10::parse(
+            "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap();
+
+        assert_eq!(36, Day10::part1(&input));
+        assert_eq!(81, Day10::part2(&input));
+    }
+}
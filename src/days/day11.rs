@@ -0,0 +1,83 @@
+use crate::{
+    error::ParseError,
+    memo::Memo,
+    parse::{self, spaced, unsigned},
+    Solution,
+};
+
+#[derive(Debug, Default)]
+pub struct Input {
+    line: Vec<usize>,
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            line: parse::finish(input, spaced(unsigned))?,
+        })
+    }
+
+    fn blink(&self, count: usize) -> usize {
+        let mut memo = Memo::new();
+
+        self.line
+            .iter()
+            .fold(0, |acc, &cur| acc + blink_count_only(cur, count, &mut memo))
+    }
+}
+
+fn blink_count_only(num: usize, count: usize, memo: &mut Memo<(usize, usize), usize>) -> usize {
+    if count == 0 {
+        return 1;
+    }
+
+    memo.get((num, count), |memo, &(num, count)| {
+        let line = if num == 0 {
+            vec![1]
+        } else {
+            let str = num.to_string();
+            if str.len().is_multiple_of(2) {
+                let (left, right) = str.split_at(str.len() / 2);
+                vec![left.parse().unwrap(), right.parse().unwrap()]
+            } else {
+                vec![num * 2024]
+            }
+        };
+
+        line.iter()
+            .fold(0, |acc, &cur| acc + blink_count_only(cur, count - 1, memo))
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.blink(25)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.blink(75)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day11::parse("125 17").unwrap();
+
+        assert_eq!(55312, Day11::part1(&input));
+        assert_eq!(65601038650482, Day11::part2(&input));
+    }
+}
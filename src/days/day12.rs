@@ -0,0 +1,351 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Index,
+};
+
+use crate::{
+    error::ParseError,
+    gen::Rng,
+    grid::Grid,
+    image::{Image, BLACK},
+    pos::Pos,
+    Solution,
+};
+
+pub struct Input {
+    map: Grid<char>,
+}
+
+impl Index<Pos> for Input {
+    type Output = char;
+
+    fn index(&self, index: Pos) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::parse(input, |c| c)?,
+        })
+    }
+
+    fn get_regions(&self) -> Vec<Vec<Pos>> {
+        let mut processed = self.map.map(|_| false);
+
+        let mut regions = vec![];
+
+        for pos in self.map.positions() {
+            if processed[pos] {
+                continue;
+            }
+
+            let mut region = vec![];
+            self.get_connecting_block(pos, &mut region, &mut processed);
+
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    fn get_connecting_block(&self, repr: Pos, region: &mut Vec<Pos>, processed: &mut Grid<bool>) {
+        region.push(repr);
+        processed[repr] = true;
+
+        for n in self.map.neighbours4(repr) {
+            if processed[n] || self[n] != self[repr] {
+                continue;
+            }
+
+            self.get_connecting_block(n, region, processed);
+        }
+    }
+
+    fn calc_peri(&self, region: &[Pos]) -> usize {
+        let mut region_new = HashSet::new();
+
+        let mut perimeter = 0;
+
+        for block in region {
+            perimeter += 4;
+            for n in self.map.neighbours4(*block) {
+                if region_new.contains(&n) {
+                    perimeter -= 2;
+                }
+            }
+            region_new.insert(*block);
+        }
+
+        perimeter
+    }
+
+    fn calc_sides(&self, region: &[Pos]) -> usize {
+        let mut side_x: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut side_y: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        for block in region {
+            let [top, bottom, left, right] = self.get_sides(block);
+
+            side_x
+                .entry(bottom.0)
+                .and_modify(|v| v.push(bottom.1))
+                .or_insert_with(|| vec![bottom.1]);
+
+            side_x
+                .entry(top.0)
+                .and_modify(|v| v.push(top.1))
+                .or_insert_with(|| vec![top.1]);
+
+            side_y
+                .entry(left.1)
+                .and_modify(|v| v.push(left.0))
+                .or_insert_with(|| vec![left.0]);
+
+            side_y
+                .entry(right.1)
+                .and_modify(|v| v.push(right.0))
+                .or_insert_with(|| vec![right.0]);
+        }
+
+        let x_sides = side_x.iter_mut().fold(0, |mut acc, (row, start_poses)| {
+            start_poses.sort();
+
+            let mut prev = vec![];
+
+            for &cur in start_poses.iter() {
+                if prev.is_empty() {
+                    prev.push(cur);
+                    continue;
+                }
+
+                let last = *prev.last().unwrap();
+
+                if cur == last {
+                    prev.pop();
+                    continue;
+                }
+
+                if cur - last > 1 && !prev.is_empty() || {
+                    if let Some(yconnector) = side_y.get(&cur) {
+                        // yconnector must have 2 {row} values or 2 {row - 1}
+                        !(yconnector.iter().filter(|&&y| y == *row).count() == 2
+                            || yconnector.iter().filter(|&&y| y == row - 1).count() == 2)
+                    } else {
+                        true
+                    }
+                } {
+                    acc += 1;
+                    prev.clear();
+                }
+
+                prev.push(cur);
+            }
+
+            if !prev.is_empty() {
+                acc += 1;
+            }
+
+            acc
+        });
+
+        let y_sides = side_y.iter_mut().fold(0, |mut acc, (col, start_poses)| {
+            start_poses.sort();
+
+            let mut prev = vec![];
+
+            for &cur in start_poses.iter() {
+                if prev.is_empty() {
+                    prev.push(cur);
+                    continue;
+                }
+
+                let last = *prev.last().unwrap();
+
+                if cur == last {
+                    prev.pop();
+                    continue;
+                }
+
+                if cur - last > 1 && !prev.is_empty() || {
+                    if let Some(xconnector) = side_x.get(&cur) {
+                        // yconnector must have 2 {row} values or 2 {row - 1}
+                        !(xconnector.iter().filter(|&&x| x == *col).count() == 2
+                            || xconnector.iter().filter(|&&x| x == col - 1).count() == 2)
+                    } else {
+                        true
+                    }
+                } {
+                    acc += 1;
+                    prev.clear();
+                }
+
+                prev.push(cur);
+            }
+
+            if !prev.is_empty() {
+                acc += 1;
+            }
+
+            acc
+        });
+
+        x_sides + y_sides
+    }
+
+    fn get_sides(&self, pos: &Pos) -> [(usize, usize); 4] {
+        let Pos { row: r, col: c } = *pos;
+
+        let top = (r, c);
+        let bottom = (r + 1, c);
+        let left = (r, c);
+        let right = (r, c + 1);
+
+        [top, bottom, left, right]
+    }
+
+    fn calc_area(&self, region: &[Pos]) -> usize {
+        region.len()
+    }
+
+    fn price(&self, bulk: bool) -> usize {
+        self.get_regions().iter().fold(0, |acc, region| {
+            let area = self.calc_area(region);
+            let peri = if bulk {
+                self.calc_sides(region)
+            } else {
+                self.calc_peri(region)
+            };
+            acc + area * peri
+        })
+    }
+}
+
+/// Every region in a colour of its own.
+pub fn picture(input: &str) -> Result<Image, ParseError> {
+    let input = Input::parse(input)?;
+    let mut rng = Rng::new(12);
+    let mut image = Image::from_grid(&input.map, 4, |_| BLACK);
+
+    for region in input.get_regions() {
+        let colour = [(); 3].map(|_| 64 + rng.below(192) as u8);
+        for &p in region.iter() {
+            image.fill(p, 4, colour);
+        }
+    }
+
+    Ok(image)
+}
+
+/// Sides counted from the fence pieces themselves rather than from corners,
+/// for checking `Input::calc_sides`.
+#[cfg(feature = "reference")]
+pub mod reference {
+    use std::collections::HashSet;
+
+    use crate::pos::{Dir, Pos};
+
+    /// Every fence piece faces out of the region. A side is a run of pieces
+    /// facing the same way, counted at the piece that starts it: the one with
+    /// no matching piece to its west or north.
+    pub fn sides(region: &[Pos]) -> usize {
+        let cells: HashSet<Pos> = region.iter().copied().collect();
+        let inside = |p: Pos, d: Dir| p.checked_add(d).is_some_and(|q| cells.contains(&q));
+        let fenced = |p: Pos, d: Dir| cells.contains(&p) && !inside(p, d);
+
+        region
+            .iter()
+            .flat_map(|&p| Dir::CARDINAL.map(|d| (p, d)))
+            .filter(|&(p, d)| fenced(p, d))
+            .filter(|&(p, d)| {
+                let before = if d.dr == 0 { Dir::N } else { Dir::W };
+                p.checked_add(before).is_none_or(|q| !fenced(q, d))
+            })
+            .count()
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.price(false)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.price(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(
+            "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+        )
+        .unwrap();
+
+        assert_eq!(1930, Day12::part1(&input));
+        assert_eq!(1206, Day12::part2(&input));
+    }
+
+    #[test]
+    fn example_enclosed() {
+        let input = Day12::parse(
+            "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+        )
+        .unwrap();
+
+        assert_eq!(1184, Day12::part1(&input));
+        assert_eq!(368, Day12::part2(&input));
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn matches_reference() {
+        use crate::gen;
+
+        gen::check_reference(
+            12,
+            0..50,
+            |_| 8,
+            Input::parse,
+            |_, input| {
+                input
+                    .get_regions()
+                    .into_iter()
+                    .map(|region| {
+                        let case = format!("region at {:?}", region[0]);
+                        (case, reference::sides(&region), input.calc_sides(&region))
+                    })
+                    .collect()
+            },
+        );
+    }
+}
@@ -1,21 +1,16 @@
 // X_A a + X_B b = X_t
 // Y_A a + Y_B b = Y_t
 
-use aoc_2024::read_input_v1;
-use nom::{
-    bytes::complete::tag,
-    character::{
-        complete::{digit1, multispace0},
-        streaming::multispace1,
-    },
-    combinator::map,
-    multi::many1,
-    sequence::{delimited, terminated},
-    IResult,
+use nom::sequence::{pair, preceded};
+
+use crate::{
+    error::ParseError,
+    parse::{self, blocks, line, lit, unsigned, PResult},
+    Solution,
 };
 
-#[derive(Debug)]
-struct Problem {
+#[derive(Debug, Clone)]
+pub struct Problem {
     ax: f64,
     ay: f64,
     bx: f64,
@@ -24,19 +19,16 @@ struct Problem {
     ty: f64,
 }
 
-fn parse_f64(input: &str) -> IResult<&str, f64> {
-    map(digit1, |s: &str| s.parse::<f64>().unwrap())(input)
+/// A line of the form `<x>123<y>456`.
+fn xy<'a>(x: &'static str, y: &'static str) -> impl FnMut(&'a str) -> PResult<'a, (f64, f64)> {
+    line(pair(preceded(lit(x), unsigned), preceded(lit(y), unsigned)))
 }
 
 impl Problem {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = multispace0(input)?;
-        let (input, ax) = delimited(tag("Button A: X+"), parse_f64, tag(", Y+"))(input)?;
-        let (input, ay) = terminated(parse_f64, multispace1)(input)?;
-        let (input, bx) = delimited(tag("Button B: X+"), parse_f64, tag(", Y+"))(input)?;
-        let (input, by) = terminated(parse_f64, multispace1)(input)?;
-        let (input, tx) = delimited(tag("Prize: X="), parse_f64, tag(", Y="))(input)?;
-        let (input, ty) = terminated(parse_f64, multispace0)(input)?;
+    fn parse(input: &str) -> PResult<'_, Self> {
+        let (input, (ax, ay)) = xy("Button A: X+", ", Y+")(input)?;
+        let (input, (bx, by)) = xy("Button B: X+", ", Y+")(input)?;
+        let (input, (tx, ty)) = xy("Prize: X=", ", Y=")(input)?;
 
         Ok((
             input,
@@ -94,8 +86,41 @@ impl Problem {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = "Button A: X+94, Y+34
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Problem>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::finish(input, blocks(Problem::parse))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.iter().map(|p| p.cost()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .map(|p| {
+                let mut p = p.clone();
+                p.recalibrate();
+                p.cost()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(
+            "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 
@@ -110,19 +135,11 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279
-";
-    let input = read_input_v1(13);
-    let (_, mut problems) = many1(Problem::parse)(&input).unwrap();
+",
+        )
+        .unwrap();
 
-    let cost = problems
-        .iter_mut()
-        .map(|p| {
-            p.recalibrate();
-            p.cost()
-        })
-        .sum::<usize>();
-
-    println!("Total cost: {}", cost);
-
-    Ok(())
+        assert_eq!(480, Day13::part1(&input));
+        assert_eq!(875318608908, Day13::part2(&input));
+    }
 }
@@ -0,0 +1,227 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use nom::{
+    character::complete::{char, space1},
+    combinator::map,
+    sequence::{preceded, separated_pair},
+};
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    image::{Image, BLACK},
+    parse::{self, lines, lit, signed, PResult},
+    pos::Pos,
+    viz::{Animation, Colour, Frame},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    p: (i32, i32),
+    v: (i32, i32),
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Map {
+    width: i32,
+    height: i32,
+    robots: Vec<Robot>,
+}
+
+impl Map {
+    fn new(width: i32, height: i32) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    fn load_robots(mut self, input: &str) -> Result<Self, ParseError> {
+        self.robots = parse::finish(input, lines(Robot::parse))?;
+        Ok(self)
+    }
+
+    fn step(&mut self, nstep: i32) {
+        for robot in self.robots.iter_mut() {
+            robot.step(nstep, self.width, self.height);
+        }
+    }
+
+    fn safety_factor(&self) -> i32 {
+        let qx = self.width / 2;
+        let qy = self.height / 2;
+
+        let mut qs = vec![0, 0, 0, 0];
+
+        for r in self.robots.iter() {
+            match (r.p.0.cmp(&qx), r.p.1.cmp(&qy)) {
+                (Ordering::Less, Ordering::Less) => qs[0] += 1,
+                (Ordering::Less, Ordering::Greater) => qs[1] += 1,
+                (Ordering::Greater, Ordering::Less) => qs[2] += 1,
+                (Ordering::Greater, Ordering::Greater) => qs[3] += 1,
+                _ => {}
+            }
+        }
+
+        qs.into_iter().filter(|&p| p > 0).product::<i32>()
+    }
+
+    fn overlapping(&self) -> bool {
+        let mut seen = HashSet::new();
+        !self.robots.iter().all(|r| seen.insert(r.p))
+    }
+
+    /// The first second where no two robots overlap, which is when they
+    /// draw the tree. Only one cycle of positions is searched; after that,
+    /// the positions repeat.
+    fn tree(&self) -> Option<usize> {
+        let mut map = self.clone();
+        (0..self.frames()).find(|_| {
+            let found = !map.overlapping();
+            map.step(1);
+            found
+        })
+    }
+
+    fn picture(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.height as usize, self.width as usize, '.');
+        for r in self.robots.iter() {
+            grid[Pos::new(r.p.1 as usize, r.p.0 as usize)] = '#';
+        }
+        grid
+    }
+}
+
+/// Robots come back to where they started after `width * height` seconds,
+/// so that is every picture there is.
+impl Animation for Map {
+    fn frames(&self) -> usize {
+        (self.width * self.height) as usize
+    }
+
+    fn frame(&mut self, i: usize) -> Frame {
+        let mut map = self.clone();
+        map.step(i as i32);
+
+        let mut caption = format!("after {i} seconds");
+        if !map.overlapping() {
+            caption.push_str(", no robots overlap");
+        }
+
+        Frame::new(map.picture())
+            .palette(&[('#', Colour::Green)])
+            .caption(caption)
+    }
+}
+
+/// The first second where the robots draw the tree, or where they start if
+/// they never do.
+pub fn picture(input: &str) -> Result<Image, ParseError> {
+    let mut map = Day14::parse(input)?;
+    map.step(map.tree().unwrap_or(0) as i32);
+    Ok(Image::from_grid(&map.picture(), 4, |&c| {
+        if c == '#' {
+            [40, 200, 60]
+        } else {
+            BLACK
+        }
+    }))
+}
+
+/// The robots, one second per frame.
+pub fn animate(input: &str) -> Result<Box<dyn Animation>, ParseError> {
+    Ok(Box::new(Day14::parse(input)?))
+}
+
+impl Robot {
+    fn parse(input: &str) -> PResult<'_, Self> {
+        let xy = || separated_pair(signed, char(','), signed);
+
+        map(
+            separated_pair(preceded(lit("p="), xy()), space1, preceded(lit("v="), xy())),
+            |(p, v)| Self { p, v },
+        )(input)
+    }
+
+    fn step(&mut self, nstep: i32, width: i32, height: i32) {
+        self.p.0 = (self.p.0 + self.v.0 * nstep).rem_euclid(width);
+        self.p.1 = (self.p.1 + self.v.1 * nstep).rem_euclid(height);
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::new(101, 103).load_robots(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        let mut map = input.clone();
+        map.step(100);
+        map.safety_factor()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        match input.tree() {
+            Some(seconds) => seconds.to_string(),
+            None => "No tree".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+        let mut map = Map::new(11, 7).load_robots(input).unwrap();
+        map.step(100);
+        assert_eq!(12, map.safety_factor());
+    }
+
+    #[test]
+    fn tree_frame_has_no_overlap() {
+        let mut map = Map::new(11, 7)
+            .load_robots("p=2,4 v=2,-3\np=0,4 v=2,-3")
+            .unwrap();
+        assert!(!map.overlapping());
+        map.step(1);
+        assert!(!map.overlapping());
+
+        let map = Map::new(11, 7)
+            .load_robots("p=2,4 v=2,-3\np=2,4 v=1,1")
+            .unwrap();
+        assert!(map.overlapping());
+    }
+
+    #[test]
+    fn no_tree() {
+        let map = Day14::parse("p=0,0 v=1,1\np=0,0 v=1,1").unwrap();
+        assert_eq!("No tree", Day14::part2(&map));
+
+        let map = Map::new(11, 7)
+            .load_robots("p=2,4 v=1,0\np=2,4 v=0,0")
+            .unwrap();
+        assert_eq!(Some(1), map.tree());
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::HashSet;
+
+use nom::{bytes::complete::is_not, sequence::separated_pair};
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    parse::{self, blank_lines, grid, lines, Error, PResult},
+    pos::{Dir, Pos},
+    viz::{Animation, Colour, Frame, Mark},
+    Solution,
+};
+
+pub struct Input {
+    grid: Grid<char>,
+    moves: Vec<Dir>,
+}
+
+impl Input {
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let map = grid("a map tile", |c| "#.O@".contains(c).then_some(c));
+        let (grid, moves) = parse::finish(s, separated_pair(map, blank_lines, moves))?;
+
+        Ok(Self { grid, moves })
+    }
+}
+
+/// Arrows, possibly spread over several lines.
+fn moves(input: &str) -> PResult<'_, Vec<Dir>> {
+    let (rest, lines) = lines(is_not("\r\n"))(input)?;
+    let mut moves = vec![];
+
+    for line in lines.into_iter().map(str::trim_end) {
+        for (i, c) in line.char_indices() {
+            match Dir::from_arrow(c) {
+                Some(dir) => moves.push(dir),
+                None => return Error::fail(&line[i..i + c.len_utf8()], "a move"),
+            }
+        }
+    }
+
+    Ok((rest, moves))
+}
+
+struct Map {
+    robot: Pos,
+    boxes: HashSet<Pos>,
+    walls: HashSet<Pos>,
+    nrow: usize,
+    ncol: usize,
+}
+
+impl Map {
+    /// The wide warehouse without the robot.
+    fn picture_twice(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.nrow, self.ncol * 2, '.');
+        for &p in self.walls.iter() {
+            grid[p] = '#';
+            grid[p + Dir::E] = '#';
+        }
+        for &p in self.boxes.iter() {
+            grid[p] = '[';
+            grid[p + Dir::E] = ']';
+        }
+
+        grid
+    }
+
+    fn from_grid(grid: &Grid<char>) -> Self {
+        let mut robot = Pos::default();
+        let mut walls = HashSet::new();
+        let mut boxes = HashSet::new();
+
+        for (p, c) in grid.iter() {
+            match c {
+                'O' => {
+                    boxes.insert(p);
+                }
+                '@' => {
+                    robot = p;
+                }
+                '#' => {
+                    walls.insert(p);
+                }
+                _ => {}
+            };
+        }
+
+        Self {
+            ncol: grid.ncol(),
+            nrow: grid.nrow(),
+            robot,
+            boxes,
+            walls,
+        }
+    }
+
+    fn from_grid_twice(grid: &Grid<char>) -> Self {
+        let mut robot = Pos::default();
+        let mut walls = HashSet::new();
+        let mut boxes = HashSet::new();
+
+        for (p, c) in grid.iter() {
+            let p = Pos::new(p.row, p.col * 2);
+            match c {
+                'O' => {
+                    boxes.insert(p);
+                }
+                '@' => {
+                    robot = p;
+                }
+                '#' => {
+                    walls.insert(p);
+                }
+                _ => {}
+            };
+        }
+
+        Self {
+            ncol: grid.ncol(),
+            nrow: grid.nrow(),
+            robot,
+            boxes,
+            walls,
+        }
+    }
+
+    fn step(&mut self, dir: Dir) {
+        let next = self.robot + dir;
+
+        if self.walls.contains(&next) {
+            return;
+        }
+
+        if self.boxes.contains(&next) && !self.try_move_box(next, dir) {
+            return;
+        }
+
+        self.robot = next;
+    }
+
+    fn step_twice(&mut self, dir: Dir) {
+        let next = self.robot + dir;
+
+        let cands = match dir.dc {
+            // ##  ##
+            // @    @
+            0 => [next + Dir::W, next].to_vec(),
+            // @##
+            1 => [next].to_vec(),
+            // ##@
+            -1 => [next + Dir::W].to_vec(),
+            _ => unreachable!(),
+        };
+
+        let mut rem_pos = HashSet::new();
+        let mut new_pos = HashSet::new();
+
+        for cand in cands {
+            if self.walls.contains(&cand) {
+                return;
+            }
+
+            if self.boxes.contains(&cand) {
+                if let Some((rem, new)) = self.try_move_box_twice(cand, dir) {
+                    rem_pos.extend(rem);
+                    new_pos.extend(new);
+                } else {
+                    return;
+                }
+            }
+        }
+
+        for pos in rem_pos {
+            self.boxes.remove(&pos);
+        }
+
+        for pos in new_pos {
+            self.boxes.insert(pos);
+        }
+
+        self.robot = next;
+    }
+
+    fn try_move_box_twice(&mut self, pos: Pos, dir: Dir) -> Option<(HashSet<Pos>, HashSet<Pos>)> {
+        let next = pos + dir;
+        let mut rem_pos = HashSet::new();
+        let mut new_pos = HashSet::new();
+
+        let cands = match dir.dc {
+            // ##  ##  ##
+            // [] []    []
+            0 => [next + Dir::E, next + Dir::W, next].to_vec(),
+            // []##
+            1 => [next + Dir::E].to_vec(),
+            // ##[]
+            -1 => [next + Dir::W].to_vec(),
+            _ => unreachable!(),
+        };
+
+        for cand in cands {
+            if self.walls.contains(&cand) {
+                return None;
+            }
+
+            if self.boxes.contains(&cand) {
+                let (rem, new) = self.try_move_box_twice(cand, dir)?;
+                rem_pos.extend(rem);
+                new_pos.extend(new);
+            }
+        }
+
+        rem_pos.insert(pos);
+        new_pos.insert(next);
+
+        Some((rem_pos, new_pos))
+    }
+
+    fn try_move_box(&mut self, pos: Pos, dir: Dir) -> bool {
+        let next = pos + dir;
+
+        if self.walls.contains(&next) {
+            return false;
+        }
+
+        if self.boxes.contains(&next) && !self.try_move_box(next, dir) {
+            return false;
+        } else {
+            self.boxes.remove(&pos);
+            self.boxes.insert(next);
+        }
+
+        true
+    }
+
+    fn gps(&self) -> i32 {
+        self.boxes
+            .iter()
+            .fold(0, |acc, p| acc + (p.row * 100 + p.col) as i32)
+    }
+}
+
+/// The wide warehouse, stepped forward from the last frame shown and
+/// replayed from the start to go back.
+struct Warehouse {
+    input: Input,
+    map: Map,
+    at: usize,
+}
+
+impl Animation for Warehouse {
+    fn frames(&self) -> usize {
+        self.input.moves.len() + 1
+    }
+
+    fn frame(&mut self, i: usize) -> Frame {
+        if i < self.at {
+            self.map = Map::from_grid_twice(&self.input.grid);
+            self.at = 0;
+        }
+        for &mv in self.input.moves[self.at..i].iter() {
+            self.map.step_twice(mv);
+        }
+        self.at = i;
+
+        let mut caption = format!("move {i} of {}", self.input.moves.len());
+        if let Some(arrow) = i.checked_sub(1).and_then(|m| self.input.moves[m].arrow()) {
+            caption.push_str(&format!(" ({arrow})"));
+        }
+        caption.push_str(&format!(", GPS sum {}", self.map.gps()));
+
+        Frame::new(self.map.picture_twice())
+            .palette(&[
+                ('#', Colour::Blue),
+                ('[', Colour::Yellow),
+                (']', Colour::Yellow),
+            ])
+            .marks([Mark::new(self.map.robot, '@', Some(Colour::Red))])
+            .caption(caption)
+    }
+}
+
+/// The robot in the wide warehouse, one move per frame.
+pub fn animate(input: &str) -> Result<Box<dyn Animation>, ParseError> {
+    let input = Input::from_str(input)?;
+    Ok(Box::new(Warehouse {
+        map: Map::from_grid_twice(&input.grid),
+        input,
+        at: 0,
+    }))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        let mut map = Map::from_grid(&input.grid);
+        for &mv in input.moves.iter() {
+            map.step(mv);
+        }
+        map.gps()
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        let mut map = Map::from_grid_twice(&input.grid);
+        for &mv in input.moves.iter() {
+            map.step_twice(mv);
+        }
+        map.gps()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let example = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+        let input = Day15::parse(example).unwrap();
+
+        assert_eq!(10092, Day15::part1(&input));
+        assert_eq!(9021, Day15::part2(&input));
+
+        let mut warehouse = animate(example).unwrap();
+        let last = warehouse.frame(warehouse.frames() - 1);
+        assert!(last.caption.ends_with("GPS sum 9021"));
+        assert_eq!(
+            "##[]...........[].##",
+            last.render(false).lines().nth(2).unwrap()
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    image::{Image, BLACK},
+    pos::{Dir, Pos},
+    search::{self, Search},
+    Solution,
+};
+
+static WALL: char = '#';
+
+#[derive(Debug)]
+pub struct Maze {
+    maze: Grid<char>,
+    start: Pos,
+    end: Pos,
+    face: Dir,
+}
+
+impl Maze {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let maze = Grid::try_parse(input, "a maze tile", |c| "#.SE".contains(c).then_some(c))?;
+        let find = |c: char| {
+            maze.find(&c)
+                .ok_or_else(|| ParseError::after(input, input.trim_end(), format!("`{c}`")))
+        };
+
+        Ok(Self {
+            start: find('S')?,
+            end: find('E')?,
+            face: Dir::E,
+            maze,
+        })
+    }
+
+    fn d(&self, pf: &Dir, prev: &Pos, nf: &Dir, next: &Pos) -> i32 {
+        if pf != nf && prev != next {
+            panic!("not allowing turn and step");
+        }
+
+        if pf == nf {
+            return 1;
+        }
+
+        // a quarter turn is square to the old facing, a half turn opposite it
+        let dot = pf.dr * nf.dr + pf.dc * nf.dc;
+
+        1000 * (match dot.cmp(&0) {
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Less => 2,
+            std::cmp::Ordering::Greater => 0,
+        })
+    }
+
+    fn h(&self, next: &Pos) -> i32 {
+        next.manhattan(self.end) as i32
+    }
+
+    fn neightbour(&self, face: &Dir, pos: &Pos) -> Vec<(Dir, Pos)> {
+        [Dir::N, Dir::S, Dir::W, Dir::E]
+            .into_iter()
+            .filter_map(|dir| {
+                if *face == dir.reverse() {
+                    return None;
+                }
+
+                if dir == *face {
+                    let next = self.maze.offset(*pos, dir)?;
+                    if self.maze[next] == WALL {
+                        return None;
+                    }
+                    Some((dir, next))
+                } else {
+                    Some((dir, *pos))
+                }
+            })
+            .collect()
+    }
+
+    fn solve(&self, face: Dir, start: Pos, end: Pos) -> Search<(Dir, Pos), i32> {
+        search::astar(
+            (face, start),
+            |&(face, pos)| {
+                self.neightbour(&face, &pos)
+                    .into_iter()
+                    .map(move |(n_face, next)| {
+                        ((n_face, next), self.d(&face, &pos, &n_face, &next))
+                    })
+            },
+            |(_, pos)| self.h(pos),
+            |&(_, pos)| pos == end,
+            true,
+        )
+    }
+
+    /// Tiles on at least one of the cheapest paths, none if there is no path.
+    fn best_tiles(&self) -> HashSet<Pos> {
+        self.solve(self.face, self.start, self.end)
+            .optimal_states()
+            .into_iter()
+            .map(|(_, pos)| pos)
+            .collect()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        match input.solve(input.face, input.start, input.end).cost() {
+            Some(cost) => cost.to_string(),
+            None => "No path".to_string(),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.best_tiles().len()
+    }
+}
+
+/// The maze with the tiles on the best paths picked out.
+pub fn picture(input: &str) -> Result<Image, ParseError> {
+    let maze = Maze::from_str(input)?;
+    let best = maze.best_tiles();
+
+    let mut image = Image::from_grid(
+        &maze.maze,
+        4,
+        |&c| {
+            if c == WALL {
+                [90, 90, 110]
+            } else {
+                BLACK
+            }
+        },
+    );
+    for &p in best.iter() {
+        image.fill(p, 4, [250, 200, 40]);
+    }
+    for p in [maze.start, maze.end] {
+        image.fill(p, 4, [220, 50, 50]);
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day16::parse(
+            "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############",
+        )
+        .unwrap();
+
+        assert_eq!("7036", Day16::part1(&input));
+        assert_eq!(45, Day16::part2(&input));
+    }
+
+    #[test]
+    fn example2() {
+        let input = Day16::parse(
+            "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################",
+        )
+        .unwrap();
+
+        assert_eq!("11048", Day16::part1(&input));
+        assert_eq!(64, Day16::part2(&input));
+    }
+
+    #[test]
+    fn end_reached_both_ways() {
+        // as cheap round the top as round the bottom, arriving facing south
+        // or north
+        let input = Day16::parse(
+            "#######
+#.....#
+#.###.#
+#S###E#
+#.###.#
+#.....#
+#######",
+        )
+        .unwrap();
+
+        assert_eq!("3008", Day16::part1(&input));
+        assert_eq!(16, Day16::part2(&input));
+    }
+
+    #[test]
+    fn no_path() {
+        let input = Day16::parse("#S#E#").unwrap();
+
+        assert_eq!("No path", Day16::part1(&input));
+        assert_eq!(0, Day16::part2(&input));
+    }
+}
@@ -0,0 +1,384 @@
+use itertools::Itertools;
+
+use nom::{
+    character::complete::char,
+    sequence::{preceded, tuple},
+};
+
+use crate::{
+    error::ParseError,
+    parse::{self, blank_lines, comma_list, key_value, line, unsigned, Error, PResult},
+    Solution,
+};
+
+/// An opcode and its operand. Instructions that take a combo operand
+/// cannot use 7, which is reserved.
+fn instruction(input: &str) -> PResult<'_, [usize; 2]> {
+    let (rest, opcode) = unsigned(input)?;
+    if opcode > 7 {
+        return Error::fail(input, "an opcode from 0 to 7");
+    }
+
+    let (rest, _) = char(',')(rest)?;
+    let (after, operand) = unsigned(rest)?;
+    match opcode {
+        1 | 3 | 4 if operand > 7 => Error::fail(rest, "an operand from 0 to 7"),
+        0 | 2 | 5..=7 if operand > 6 => Error::fail(rest, "a combo operand from 0 to 6"),
+        _ => Ok((after, [opcode, operand])),
+    }
+}
+
+/// `a` divided by two to the power `c`.
+fn shift(a: u64, c: u64) -> u64 {
+    u32::try_from(c)
+        .ok()
+        .and_then(|c| a.checked_shr(c))
+        .unwrap_or(0)
+}
+
+/// Instructions run before giving up on a program that may never halt.
+const STEPS: usize = 100_000;
+
+/// Values of A tried before giving up on finding one that prints the
+/// program.
+const TRIES: usize = 10_000;
+
+/// A program still running after `STEPS` instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoHalt;
+
+impl std::fmt::Display for NoHalt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The program does not halt within {STEPS} steps")
+    }
+}
+
+impl std::error::Error for NoHalt {}
+
+#[derive(Debug, Clone)]
+pub struct Sim {
+    pc: usize,
+    program: Vec<usize>,
+    out: Vec<u64>,
+    reg: [u64; 3],
+}
+
+impl Sim {
+    fn load(input: &str) -> Result<Self, ParseError> {
+        let (a, b, c, program) = parse::finish(
+            input,
+            tuple((
+                line(key_value("Register A", unsigned)),
+                line(key_value("Register B", unsigned)),
+                line(key_value("Register C", unsigned)),
+                preceded(blank_lines, key_value("Program", comma_list(instruction))),
+            )),
+        )?;
+
+        Ok(Sim {
+            program: program.concat(),
+            pc: 0,
+            reg: [a, b, c],
+            out: vec![],
+        })
+    }
+
+    fn run_once(&mut self) -> bool {
+        if self.pc + 1 >= self.program.len() {
+            return true;
+        }
+
+        let ops = (self.program[self.pc], self.program[self.pc + 1]);
+
+        self.execute(ops);
+        false
+    }
+
+    fn init(&mut self) {
+        self.pc = 0;
+        self.out = vec![];
+    }
+
+    /// Runs from the start until the program halts, giving up after
+    /// `STEPS` instructions.
+    fn run(&mut self) -> Result<(), NoHalt> {
+        self.init();
+        for _ in 0..STEPS {
+            if self.run_once() {
+                return Ok(());
+            }
+        }
+
+        Err(NoHalt)
+    }
+
+    /// Runs from register A set to `a` and returns what is printed, or
+    /// `None` if the program prints more than `limit` values or runs for
+    /// too long.
+    fn output_for(&self, a: u64, limit: usize) -> Option<Vec<u64>> {
+        let mut sim = self.clone();
+        sim.init();
+        sim.reg[0] = a;
+
+        for _ in 0..STEPS {
+            if sim.out.len() > limit {
+                return None;
+            }
+            if sim.run_once() {
+                return Some(sim.out);
+            }
+        }
+
+        None
+    }
+
+    /// The value of a combo operand. The reserved 7 is rejected when the
+    /// program is loaded.
+    fn combo(&self, op: usize) -> Option<u64> {
+        match op {
+            0..=3 => Some(op as u64),
+            4..=6 => Some(self.reg[op - 4]),
+            _ => None,
+        }
+    }
+
+    /// Runs one instruction. Anything the parser would have rejected halts
+    /// the machine.
+    fn execute(&mut self, op: (usize, usize)) {
+        let halt = self.program.len();
+        let c = match self.combo(op.1) {
+            Some(c) => c,
+            None if matches!(op.0, 1 | 3 | 4) => 0,
+            None => {
+                self.pc = halt;
+                return;
+            }
+        };
+
+        match op.0 {
+            0 => self.reg[0] = shift(self.reg[0], c),
+            1 => self.reg[1] ^= op.1 as u64,
+            2 => self.reg[1] = c % 8,
+            3 => {
+                if self.reg[0] != 0 {
+                    self.pc = op.1;
+                    return;
+                }
+            }
+            4 => self.reg[1] ^= self.reg[2],
+            5 => self.out.push(c % 8),
+            6 => self.reg[1] = shift(self.reg[0], c),
+            7 => self.reg[2] = shift(self.reg[0], c),
+            _ => {
+                self.pc = halt;
+                return;
+            }
+        }
+
+        self.pc += 2
+    }
+
+    /// The lowest positive A for which the program prints `out`, if there
+    /// is one. This works for programs shaped like the puzzle's, which print
+    /// one value and shift A right by three bits each time round. A is then
+    /// built three bits at a time from the last value printed. Every
+    /// candidate is checked by running the program, so `None` is returned
+    /// for programs of any other shape rather than a wrong answer.
+    fn unprogram(&self, out: &[u64]) -> Option<u64> {
+        // depth first with the lowest bits tried first, so the first A
+        // found is the lowest
+        let mut tries = 0;
+        let mut stack = vec![(0u64, out.len())];
+
+        while let Some((a, printed_from)) = stack.pop() {
+            if printed_from == 0 {
+                if a > 0 {
+                    return Some(a);
+                }
+                continue;
+            }
+
+            let from = printed_from - 1;
+            for bits in (0..8).rev() {
+                let Some(next) = a.checked_mul(8).map(|a| a + bits) else {
+                    continue;
+                };
+
+                tries += 1;
+                if tries > TRIES {
+                    return None;
+                }
+                if self.output_for(next, out.len()).as_deref() == Some(&out[from..]) {
+                    stack.push((next, from));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Part 2 by brute force, running the program for each A in turn; only
+/// quick enough for programs that print a few values.
+#[cfg(feature = "reference")]
+pub mod reference {
+    use super::Sim;
+
+    /// The lowest positive register A up to `TRIES` for which the program
+    /// halts having printed `out`, found by running it for every A in turn.
+    pub fn unprogram(sim: &Sim, out: &[u64]) -> Option<u64> {
+        (1..=super::TRIES as u64).find(|&a| {
+            let mut sim = sim.clone();
+            sim.reg = [a, 0, 0];
+            sim.run().is_ok() && sim.out == out
+        })
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Sim;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Sim::load(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let mut sim = input.clone();
+        match sim.run() {
+            Ok(()) => sim.out.iter().join(","),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        let out = input.program.iter().map(|&p| p as u64).collect::<Vec<_>>();
+        match input.unprogram(&out) {
+            Some(a) => a.to_string(),
+            None => "No A prints the program".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day17::parse(
+            "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0",
+        )
+        .unwrap();
+
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", Day17::part1(&input));
+    }
+
+    #[test]
+    fn unprogram_quines() {
+        let input = Day17::parse(
+            "Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 2,4,1,2,7,5,4,3,0,3,1,7,5,5,3,0",
+        )
+        .unwrap();
+
+        let a = Day17::part2(&input);
+        let mut sim = input.clone();
+        sim.reg = [a.parse().unwrap(), 0, 0];
+        sim.run().unwrap();
+
+        assert_eq!("190384609508367", a);
+        assert_eq!(
+            input.program.iter().map(|&p| p as u64).collect::<Vec<_>>(),
+            sim.out
+        );
+
+        let input = Day17::parse(
+            "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+        )
+        .unwrap();
+        assert_eq!("117440", Day17::part2(&input));
+    }
+
+    #[test]
+    fn unsupported_programs() {
+        // prints A forever without changing it
+        let input = Day17::parse(
+            "Register A: 1
+Register B: 0
+Register C: 0
+
+Program: 5,4,3,0",
+        )
+        .unwrap();
+        assert_eq!(None, input.unprogram(&[5, 4, 3, 0]));
+        assert_eq!(None, input.unprogram(&[]));
+        assert_eq!(NoHalt.to_string(), Day17::part1(&input));
+    }
+
+    #[test]
+    fn rejects_bad_programs() {
+        let program = |p: &str| {
+            Day17::parse(&format!(
+                "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {p}"
+            ))
+        };
+
+        assert!(program("0,1,9,0")
+            .unwrap_err()
+            .to_string()
+            .ends_with("expected an opcode from 0 to 7, found `9,0`"));
+        assert!(program("1,7,0,7")
+            .unwrap_err()
+            .to_string()
+            .ends_with("expected a combo operand from 0 to 6, found `7`"));
+        assert!(program("1,8").is_err());
+        assert!(program("0,1,5").is_err());
+        assert!(program("1,7,4,7,3,7").is_ok());
+    }
+
+    #[test]
+    fn huge_shifts() {
+        let mut input = Day17::parse(
+            "Register A: 5
+Register B: 100
+Register C: 0
+
+Program: 0,5,5,4",
+        )
+        .unwrap();
+        assert_eq!("0", Day17::part1(&input));
+
+        // a jump to an odd address reads past the end and halts
+        input.program = vec![3, 1, 5, 4];
+        assert_eq!("", Day17::part1(&input));
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn matches_reference() {
+        use crate::gen;
+
+        let digits = |seed| 1 + seed as usize % 4;
+        gen::check_reference(17, 0..20, digits, Sim::load, |_, mut sim| {
+            let a = sim.reg[0];
+            sim.run().unwrap();
+            let expected = reference::unprogram(&sim, &sim.out);
+            vec![(format!("A = {a}"), expected, sim.unprogram(&sim.out))]
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use nom::{
+    character::complete::char,
+    combinator::{map, verify},
+    error::context,
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    image::{Image, BLACK},
+    parse::{self, lines, unsigned},
+    pos::Pos,
+    search::{self, Search},
+    Solution,
+};
+
+#[derive(Clone)]
+pub struct Memory {
+    corrupted: Grid<bool>,
+    fb_all: Vec<Pos>,
+}
+
+impl Memory {
+    fn new(ncol: usize, nrow: usize) -> Self {
+        Memory {
+            corrupted: Grid::new(nrow, ncol, false),
+            fb_all: vec![],
+        }
+    }
+
+    fn load(mut self, input: &str) -> Result<Self, ParseError> {
+        let pos = map(separated_pair(unsigned, char(','), unsigned), |(x, y)| {
+            Pos::new(y, x)
+        });
+        let pos = context(
+            "a position in memory",
+            verify(pos, |&p| self.corrupted.contains(p)),
+        );
+
+        self.fb_all = parse::finish(input, lines(pos))?;
+        Ok(self)
+    }
+
+    fn neighbours(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.corrupted
+            .neighbours4(p)
+            .filter(|&n| !self.corrupted[n])
+    }
+
+    fn fall(&mut self, num: usize) {
+        self.corrupted = Grid::new(self.corrupted.nrow(), self.corrupted.ncol(), false);
+        for &p in self.fb_all.iter().take(num) {
+            self.corrupted[p] = true;
+        }
+    }
+
+    fn first_blocking(&mut self, from: usize) -> Option<Pos> {
+        for i in from..=self.fb_all.len() {
+            self.fall(i);
+            if self.solve().is_none() {
+                return Some(self.fb_all[i - 1]);
+            }
+        }
+
+        None
+    }
+
+    fn search(&self) -> Search<Pos, usize> {
+        let end = Pos::new(self.corrupted.nrow() - 1, self.corrupted.ncol() - 1);
+
+        search::bfs(
+            Pos::default(),
+            |&p| self.neighbours(p),
+            |&p| p == end,
+            false,
+        )
+    }
+
+    fn solve(&self) -> Option<usize> {
+        self.search().cost()
+    }
+}
+
+/// Memory after the first kilobyte has fallen, with the shortest way out.
+pub fn picture(input: &str) -> Result<Image, ParseError> {
+    let mut memory = Day18::parse(input)?;
+    memory.fall(1024);
+
+    let mut image = Image::from_grid(
+        &memory.corrupted,
+        8,
+        |&c| {
+            if c {
+                [170, 40, 40]
+            } else {
+                BLACK
+            }
+        },
+    );
+    for p in memory.search().path().unwrap_or_default() {
+        image.fill(p, 8, [60, 200, 90]);
+    }
+
+    Ok(image)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Memory::new(71, 71).load(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let mut memory = input.clone();
+        memory.fall(1024);
+        match memory.solve() {
+            Some(steps) => steps.to_string(),
+            None => "No path".to_string(),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        match input.clone().first_blocking(1024) {
+            Some(p) => format!("{},{}", p.col, p.row),
+            None => "No blocking byte".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+        let mut memory = Memory::new(7, 7).load(input).unwrap();
+
+        memory.fall(12);
+        assert_eq!(Some(22), memory.solve());
+        assert_eq!(Some(Pos::new(1, 6)), memory.first_blocking(12));
+    }
+
+    #[test]
+    fn no_path() {
+        let wall = (0..71).map(|x| format!("{x},1\n")).collect::<String>();
+        let input = Day18::parse(&wall).unwrap();
+
+        assert_eq!("No path", Day18::part1(&input));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use nom::{character::complete::alpha1, combinator::map, sequence::separated_pair};
+
+use crate::{
+    error::ParseError,
+    memo::{Dense, Memo},
+    parse::{self, blank_lines, comma_list, line, lines},
+    Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    rules: HashSet<String>,
+    longest: usize,
+    checklist: Vec<String>,
+}
+
+impl Input {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let towel = || map(alpha1, str::to_string);
+        let (rules, checklist) = parse::finish(
+            input,
+            separated_pair(line(comma_list(towel())), blank_lines, lines(towel())),
+        )?;
+
+        Ok(Self {
+            longest: rules.iter().map(String::len).max().unwrap_or(0),
+            rules: rules.into_iter().collect(),
+            checklist,
+        })
+    }
+
+    /// Ways to make `design[from..]` out of towels.
+    fn arrangements(
+        &self,
+        design: &str,
+        from: usize,
+        memo: &mut Memo<usize, usize, Dense<usize, usize>>,
+    ) -> usize {
+        if from == design.len() {
+            return 1;
+        }
+
+        memo.get(from, |memo, &from| {
+            (from + 1..=design.len().min(from + self.longest))
+                .filter(|&to| self.rules.contains(&design[from..to]))
+                .map(|to| self.arrangements(design, to, memo))
+                .sum()
+        })
+    }
+
+    fn check(&self, design: &str) -> usize {
+        self.arrangements(design, 0, &mut Memo::with_table(Dense::new(|&i| i)))
+    }
+
+    fn check_all(&self) -> usize {
+        self.checklist.iter().filter(|c| self.check(c) > 0).count()
+    }
+
+    fn count_all(&self) -> usize {
+        self.checklist.iter().map(|c| self.check(c)).sum()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.check_all()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.count_all()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day19::parse(
+            "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb",
+        )
+        .unwrap();
+
+        assert_eq!(6, Day19::part1(&input));
+        assert_eq!(16, Day19::part2(&input));
+    }
+}
@@ -0,0 +1,577 @@
+use std::{collections::BTreeMap, fmt::Display, ops::RangeInclusive};
+
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    parse::{self, lines, signed, spaced},
+    Solution,
+};
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::finish(input, lines(spaced(signed)))
+}
+
+/// Reports made safe by removing at most `max_tolerance` levels.
+fn part1(levelss: &[Vec<i32>], max_tolerance: usize) -> usize {
+    let policy = SafetyPolicy::default().tolerance(max_tolerance);
+    levelss
+        .iter()
+        .filter(|levels| policy.is_safe(levels))
+        .count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Increasing => write!(f, "increasing"),
+            Direction::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+/// Which ways a report may go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as it keeps to it.
+    Either,
+    /// Any way at each step.
+    Mixed,
+}
+
+/// Why a pair of levels is unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Reason {
+    StepTooSmall,
+    StepTooLarge,
+    /// Against the one direction the policy allows.
+    WrongDirection,
+    /// Against the direction the report started in.
+    ChangedDirection,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reason::StepTooSmall => write!(f, "step too small"),
+            Reason::StepTooLarge => write!(f, "step too large"),
+            Reason::WrongDirection => write!(f, "wrong direction"),
+            Reason::ChangedDirection => write!(f, "changed direction"),
+        }
+    }
+}
+
+/// The first unsafe pair of levels in a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the first level of the pair.
+    pub index: usize,
+    pub pair: (i32, i32),
+    pub reason: Reason,
+}
+
+/// What makes a report safe. The default is the puzzle's: steps of one to
+/// three, all one way, with nothing removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub trend: Trend,
+    /// How many levels may be removed.
+    pub tolerance: usize,
+}
+
+const PUZZLE: SafetyPolicy = SafetyPolicy {
+    min_step: 1,
+    max_step: 3,
+    trend: Trend::Either,
+    tolerance: 0,
+};
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        PUZZLE
+    }
+}
+
+/// The fewest levels to take out of a report to make it safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dampened {
+    /// Indices of the levels taken out, in order.
+    pub removed: Vec<usize>,
+    /// Which way what is left goes, or `None` if fewer than two levels are
+    /// left or the trend is [`Trend::Mixed`].
+    pub direction: Option<Direction>,
+}
+
+impl SafetyPolicy {
+    /// Steps between `min` and `max` inclusive, ignoring their sign.
+    pub fn steps(mut self, min: i32, max: i32) -> Self {
+        self.min_step = min;
+        self.max_step = max;
+        self
+    }
+
+    pub fn trend(mut self, trend: Trend) -> Self {
+        self.trend = trend;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: usize) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// The levels a step in the direction of `sign` may reach `level` from,
+    /// or `None` if no level can. Worked out in `i64` so that extreme levels
+    /// and steps cannot overflow.
+    fn before(&self, level: i32, sign: i32) -> Option<RangeInclusive<i32>> {
+        let (level, min, max) = (
+            i64::from(level),
+            i64::from(self.min_step),
+            i64::from(self.max_step),
+        );
+        let (lo, hi) = if sign > 0 {
+            (level - max, level - min)
+        } else {
+            (level + min, level + max)
+        };
+        let clamp = |v: i64| v.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+
+        (lo <= hi && hi >= i32::MIN.into() && lo <= i32::MAX.into()).then(|| clamp(lo)..=clamp(hi))
+    }
+
+    /// The indices of the longest run of `levels` whose steps are allowed,
+    /// going up by them for each of `signs`. Each level looks up the runs
+    /// ending on values within a step of it, so this is O(n log n) while
+    /// few of the values seen fall in that window, and O(n²) at worst.
+    fn longest_run(&self, levels: &[i32], signs: &[i32]) -> Vec<usize> {
+        // the longest run so far ending on each value, and where it ends
+        let mut best = BTreeMap::<i32, (usize, usize)>::new();
+        let mut prev = vec![None; levels.len()];
+        let mut end = None::<(usize, usize)>;
+
+        for (i, &level) in levels.iter().enumerate() {
+            let from = signs
+                .iter()
+                .filter_map(|&sign| self.before(level, sign))
+                .flat_map(|range| best.range(range).map(|(_, run)| run))
+                .max();
+            let len = from.map_or(1, |&(len, _)| len + 1);
+            prev[i] = from.map(|&(_, j)| j);
+
+            let entry = best.entry(level).or_insert((len, i));
+            if len >= entry.0 {
+                *entry = (len, i);
+            }
+            if end.is_none_or(|(longest, _)| len > longest) {
+                end = Some((len, i));
+            }
+        }
+
+        let mut run = vec![];
+        let mut at = end.map(|(_, i)| i);
+        while let Some(i) = at {
+            run.push(i);
+            at = prev[i];
+        }
+        run.reverse();
+        run
+    }
+
+    /// The fewest levels to take out of `levels` to make it safe.
+    pub fn dampen(&self, levels: &[i32]) -> Dampened {
+        let run = |sign, direction| (self.longest_run(levels, &[sign]), Some(direction));
+        let (kept, direction) = match self.trend {
+            Trend::Increasing => run(1, Direction::Increasing),
+            Trend::Decreasing => run(-1, Direction::Decreasing),
+            Trend::Either => {
+                let (up, down) = (
+                    run(1, Direction::Increasing),
+                    run(-1, Direction::Decreasing),
+                );
+                if up.0.len() >= down.0.len() {
+                    up
+                } else {
+                    down
+                }
+            }
+            Trend::Mixed => (self.longest_run(levels, &[1, -1]), None),
+        };
+
+        let mut removed = vec![true; levels.len()];
+        for &i in kept.iter() {
+            removed[i] = false;
+        }
+
+        Dampened {
+            removed: (0..levels.len()).filter(|&i| removed[i]).collect(),
+            direction: direction.filter(|_| kept.len() >= 2),
+        }
+    }
+
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        self.dampen(levels).removed.len() <= self.tolerance
+    }
+
+    /// The first pair of `levels` that breaks the rules, with nothing
+    /// removed. Direction is checked before the size of the step, which is
+    /// taken in `i64` like in `before`.
+    pub fn first_violation(&self, levels: &[i32]) -> Option<Violation> {
+        let mut started = None;
+
+        levels.windows(2).enumerate().find_map(|(index, pair)| {
+            let diff = i64::from(pair[1]) - i64::from(pair[0]);
+            let sign = diff.signum();
+            let reason = match self.trend {
+                Trend::Increasing if sign < 0 => Some(Reason::WrongDirection),
+                Trend::Decreasing if sign > 0 => Some(Reason::WrongDirection),
+                Trend::Either if sign != 0 && *started.get_or_insert(sign) != sign => {
+                    Some(Reason::ChangedDirection)
+                }
+                _ if diff.abs() < self.min_step.into() => Some(Reason::StepTooSmall),
+                _ if diff.abs() > self.max_step.into() => Some(Reason::StepTooLarge),
+                _ => None,
+            };
+
+            reason.map(|reason| Violation {
+                index,
+                pair: (pair[0], pair[1]),
+                reason,
+            })
+        })
+    }
+
+    /// The first violation of every report that is unsafe even with the
+    /// tolerance, and how often each reason came up.
+    pub fn diagnose(&self, levelss: &[Vec<i32>]) -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+        for (report, levels) in levelss.iter().enumerate() {
+            if self.is_safe(levels) {
+                continue;
+            }
+            let violation = self
+                .first_violation(levels)
+                .expect("an unsafe report has an unsafe pair");
+
+            *diagnostics.reasons.entry(violation.reason).or_default() += 1;
+            diagnostics.unsafe_reports.push((report, violation));
+        }
+
+        diagnostics
+    }
+
+    /// One line saying whether `levels` is safe and which levels have to
+    /// go to make it so.
+    pub fn explain(&self, levels: &[i32]) -> String {
+        let dampened = self.dampen(levels);
+        let report = levels.iter().join(" ");
+        let removed = dampened
+            .removed
+            .iter()
+            .map(|&i| format!("the {} at index {i}", levels[i]))
+            .join(" and ");
+        let direction = dampened
+            .direction
+            .map_or(String::new(), |d| format!(", {d}"));
+
+        if dampened.removed.is_empty() {
+            format!("{report}: safe{direction}")
+        } else if dampened.removed.len() <= self.tolerance {
+            format!("{report}: safe without {removed}{direction}")
+        } else {
+            format!("{report}: unsafe, would need {removed} removed")
+        }
+    }
+}
+
+/// Unsafe reports across an input, by index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub unsafe_reports: Vec<(usize, Violation)>,
+    pub reasons: BTreeMap<Reason, usize>,
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (report, v) in self.unsafe_reports.iter() {
+            writeln!(
+                f,
+                "report {report}: {} {} at index {}, {}",
+                v.pair.0, v.pair.1, v.index, v.reason
+            )?;
+        }
+        for (reason, count) in self.reasons.iter() {
+            writeln!(f, "{reason}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Safety straight from the puzzle text: a report passes if removing some
+/// `k` of its levels leaves it all increasing or all decreasing by 1 to 3,
+/// trying every choice of levels to remove.
+#[cfg(feature = "reference")]
+pub mod reference {
+    use itertools::Itertools;
+
+    /// Steadily rising or falling by one to three each step.
+    pub fn is_safe(levels: &[i32]) -> bool {
+        let diffs = levels.windows(2).map(|w| w[1] - w[0]).collect_vec();
+        diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+    }
+
+    /// Reports made safe by removing at most `max_tolerance` levels, trying
+    /// every way of removing them.
+    pub fn part1(levelss: &[Vec<i32>], max_tolerance: usize) -> usize {
+        levelss
+            .iter()
+            .filter(|levels| {
+                (0..=max_tolerance.min(levels.len())).any(|k| {
+                    (0..levels.len()).combinations(k).any(|removed| {
+                        let kept = (0..levels.len())
+                            .filter(|i| !removed.contains(i))
+                            .map(|i| levels[i])
+                            .collect_vec();
+                        is_safe(&kept)
+                    })
+                })
+            })
+            .count()
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input, 0)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part1(input, 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn repeated() {
+        assert_eq!(1, part1(&parse("1 1 2 3 4").unwrap(), 1))
+    }
+
+    #[test]
+    fn repeated_too_much() {
+        assert_eq!(0, part1(&parse("1 1 1 3 4").unwrap(), 1))
+    }
+
+    #[test]
+    fn example() {
+        let input = Day2::parse(
+            "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9",
+        )
+        .unwrap();
+
+        assert_eq!(2, Day2::part1(&input));
+        assert_eq!(4, Day2::part2(&input));
+    }
+
+    #[test]
+    fn dampens_any_count() {
+        let levelss = parse("1 7 5\n3 2 3 6\n1 9 2 8 3 4\n5 4 9 9 9 3").unwrap();
+        assert_eq!(0, part1(&levelss, 0));
+        assert_eq!(2, part1(&levelss, 1));
+        assert_eq!(3, part1(&levelss, 2));
+        assert_eq!(4, part1(&levelss, 3));
+
+        assert_eq!(
+            Dampened {
+                removed: vec![1, 3],
+                direction: Some(Direction::Increasing)
+            },
+            SafetyPolicy::default().dampen(&levelss[2])
+        );
+        assert_eq!(
+            Dampened {
+                removed: vec![],
+                direction: None
+            },
+            SafetyPolicy::default().dampen(&[4])
+        );
+    }
+
+    #[test]
+    fn extreme_steps() {
+        let wide = SafetyPolicy::default().steps(1, i32::MAX);
+        assert!(wide.is_safe(&[i32::MIN, -1, i32::MAX - 1]));
+        assert!(wide.is_safe(&[i32::MAX, 0, -i32::MAX]));
+        assert!(!wide.is_safe(&[i32::MIN, 0, i32::MAX]));
+        let diagnostics = wide.diagnose(&[vec![i32::MIN, 0, i32::MAX]]);
+        assert_eq!(
+            vec![(
+                0,
+                Violation {
+                    index: 0,
+                    pair: (i32::MIN, 0),
+                    reason: Reason::StepTooLarge
+                }
+            )],
+            diagnostics.unsafe_reports
+        );
+        assert_eq!(None, wide.first_violation(&[i32::MAX, 0, -i32::MAX]));
+
+        let five = SafetyPolicy::default().steps(5, 5);
+        assert!(!five.is_safe(&[i32::MIN + 1, i32::MIN]));
+        assert!(five.is_safe(&[i32::MAX - 5, i32::MAX]));
+        assert!(!SafetyPolicy::default().steps(3, 1).is_safe(&[1, 3]));
+    }
+
+    #[test]
+    fn policies() {
+        let levels = [1, 2, 2, 5, 3, 4];
+        let policy = SafetyPolicy::default();
+        assert!(!policy.tolerance(1).is_safe(&levels));
+        assert!(policy.tolerance(2).is_safe(&levels));
+        assert!(policy.steps(0, 3).tolerance(1).is_safe(&levels));
+        assert!(policy.trend(Trend::Mixed).is_safe(&[1, 3, 2, 4, 3]));
+        assert!(!policy.trend(Trend::Decreasing).is_safe(&[1, 2, 3]));
+
+        let mixed = policy.trend(Trend::Mixed).tolerance(1);
+        assert_eq!(
+            Dampened {
+                removed: vec![2],
+                direction: None
+            },
+            mixed.dampen(&[5, 3, 9, 4])
+        );
+
+        let violation = |policy: SafetyPolicy, levels: &[i32]| {
+            policy.first_violation(levels).map(|v| (v.index, v.reason))
+        };
+        assert_eq!(None, violation(policy, &[7, 6, 4, 2, 1]));
+        assert_eq!(
+            Some((1, Reason::ChangedDirection)),
+            violation(policy, &[1, 3, 2, 4, 5])
+        );
+        assert_eq!(
+            Some((0, Reason::WrongDirection)),
+            violation(policy.trend(Trend::Increasing), &[7, 6, 4])
+        );
+        assert_eq!(
+            Some((2, Reason::StepTooSmall)),
+            violation(policy.trend(Trend::Mixed), &[8, 6, 7, 7])
+        );
+    }
+
+    #[test]
+    fn diagnoses() {
+        let input = Day2::parse(
+            "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9",
+        )
+        .unwrap();
+
+        let diagnostics = SafetyPolicy::default().diagnose(&input);
+        assert_eq!(
+            "report 1: 2 7 at index 1, step too large
+report 2: 6 2 at index 2, step too large
+report 3: 3 2 at index 1, changed direction
+report 4: 4 4 at index 2, step too small
+step too small: 1
+step too large: 2
+changed direction: 1
+",
+            diagnostics.to_string()
+        );
+
+        let dampened = SafetyPolicy::default().tolerance(1).diagnose(&input);
+        assert_eq!(
+            vec![1, 2],
+            dampened
+                .unsafe_reports
+                .iter()
+                .map(|r| r.0)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn explains() {
+        let explained = parse(
+            "7 6 4 2 1
+1 2 7 8 9
+1 3 2 4 5",
+        )
+        .unwrap()
+        .iter()
+        .map(|levels| SafetyPolicy::default().tolerance(1).explain(levels))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "7 6 4 2 1: safe, decreasing",
+                "1 2 7 8 9: unsafe, would need the 1 at index 0 and the 2 at index 1 removed",
+                "1 3 2 4 5: safe without the 3 at index 1, increasing",
+            ],
+            explained
+        );
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn matches_reference() {
+        use crate::gen::{self, Rng};
+        use itertools::Itertools;
+
+        gen::check_reference(
+            2,
+            0..20,
+            |_| 50,
+            parse,
+            |seed, mut levelss| {
+                // Short reports of small levels hit the ties a real input rarely has.
+                let mut rng = Rng::new(seed);
+                levelss.extend((0..200).map(|_| {
+                    (0..rng.between(1, 7))
+                        .map(|_| rng.between(1, 9) as i32)
+                        .collect_vec()
+                }));
+
+                (0..=3)
+                    .map(|k| {
+                        let expected = reference::part1(&levelss, k);
+                        (format!("tolerance {k}"), expected, part1(&levelss, k))
+                    })
+                    .collect()
+            },
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    pos::{Dir, Pos},
+    search, Solution,
+};
+
+#[derive(Debug)]
+pub struct Maze {
+    map: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+static WALL: char = '#';
+
+impl Maze {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::try_parse(input, "a track tile", |c| "#.SE".contains(c).then_some(c))?;
+        let find = |c: char| {
+            map.find(&c)
+                .ok_or_else(|| ParseError::after(input, input.trim_end(), format!("`{c}`")))
+        };
+
+        Ok(Self {
+            start: find('S')?,
+            end: find('E')?,
+            map,
+        })
+    }
+
+    fn neighbours(&self, pos: Pos) -> Vec<Pos> {
+        let mut neighbours = vec![];
+
+        for dir in [Dir::E, Dir::W, Dir::S, Dir::N] {
+            let Some(new_pos) = self.map.offset(pos, dir) else {
+                continue;
+            };
+
+            if new_pos == self.end {
+                neighbours.push(new_pos);
+                break;
+            }
+
+            if self.map[new_pos] == WALL {
+                continue;
+            }
+
+            neighbours.push(new_pos);
+        }
+
+        neighbours
+    }
+
+    fn solve(&self, target: i32, seconds: i32) -> i32 {
+        let g_score = search::bfs(
+            self.start,
+            |&pos| self.neighbours(pos),
+            |&pos| pos == self.end,
+            false,
+        )
+        .dist;
+
+        g_score
+            .keys()
+            .sorted()
+            .combinations(2)
+            .filter(|a| {
+                let skipped = a[0].manhattan(*a[1]) as i32;
+                skipped <= seconds
+                    && g_score[a[0]].abs_diff(g_score[a[1]]) as i32 - skipped >= target
+            })
+            .count() as i32
+    }
+}
+
+/// Cheats counted pair by pair: every two track tiles close enough to cheat
+/// between, with distances from a plain breadth-first search.
+#[cfg(feature = "reference")]
+pub mod reference {
+    use std::collections::{HashMap, VecDeque};
+
+    use super::{Maze, WALL};
+    use crate::pos::Pos;
+
+    fn distances(maze: &Maze, from: Pos) -> HashMap<Pos, usize> {
+        let mut dist = HashMap::from([(from, 0)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(pos) = queue.pop_front() {
+            for next in maze.map.neighbours4(pos) {
+                if maze.map[next] != WALL && !dist.contains_key(&next) {
+                    dist.insert(next, dist[&pos] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        dist
+    }
+
+    /// Cheats of at most `seconds` that save at least `target`: every pair of
+    /// track tiles, timed as start to the first, through the walls, then on
+    /// from the second to the end.
+    pub fn solve(maze: &Maze, target: i32, seconds: i32) -> i32 {
+        let from_start = distances(maze, maze.start);
+        let to_end = distances(maze, maze.end);
+        let best = from_start[&maze.end];
+
+        let mut count = 0;
+        for (&a, &to_a) in &from_start {
+            for (&b, &from_b) in &to_end {
+                let skipped = a.manhattan(b);
+                let time = to_a + skipped + from_b;
+                if skipped <= seconds as usize && time + target as usize <= best {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Maze;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        input.solve(100, 2)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        input.solve(100, 20)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let maze = Day20::parse(
+            "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############",
+        )
+        .unwrap();
+
+        assert_eq!(5, maze.solve(20, 2));
+        assert_eq!(1, maze.solve(64, 2));
+        assert_eq!(285, maze.solve(50, 20));
+        assert_eq!(3, maze.solve(76, 20));
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn matches_reference() {
+        use crate::gen;
+
+        gen::check_reference(
+            20,
+            0..10,
+            |_| 15,
+            Maze::from_str,
+            |_, maze| {
+                [(2, 2), (10, 2), (4, 6), (20, 20)]
+                    .into_iter()
+                    .map(|(target, seconds)| {
+                        let case = format!("saving {target} in {seconds}");
+                        let expected = reference::solve(&maze, target, seconds);
+                        (case, expected, maze.solve(target, seconds))
+                    })
+                    .collect()
+            },
+        );
+    }
+}
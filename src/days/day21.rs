@@ -1,10 +1,23 @@
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{HashMap, HashSet},
     iter::repeat_n,
 };
 
 use itertools::Itertools;
 
+use nom::{
+    character::complete::char,
+    combinator::{map, recognize},
+    sequence::pair,
+};
+
+use crate::{
+    error::ParseError,
+    memo::Memo,
+    parse::{self, lines, unsigned},
+    Solution,
+};
+
 static NUMPAD_V: [[char; 3]; 4] = [
     ['7', '8', '9'],
     ['4', '5', '6'],
@@ -344,11 +357,11 @@ impl Map {
     fn calculate_complexity(&self, input: &str, depth: usize) -> usize {
         let input_num = input.trim_end_matches('A').parse::<usize>().unwrap();
         let dpads = self.all_numpad_moves(input);
-        let mut cache = HashMap::new();
+        let mut memo = Memo::new();
 
         let complexity = dpads
             .iter()
-            .map(|input| self.solve_depth(input.to_string(), &mut cache, depth))
+            .map(|input| self.solve_depth(input.to_string(), &mut memo, depth))
             .min()
             .unwrap();
 
@@ -358,48 +371,51 @@ impl Map {
     fn solve_depth(
         &self,
         num: String,
-        cache: &mut HashMap<(String, usize), usize>,
+        memo: &mut Memo<(String, usize), usize>,
         count: usize,
     ) -> usize {
         if count == 0 {
             return num.len();
         }
 
-        let len = match cache.get(&(num.clone(), count)) {
-            Some(str) => *str,
-            None => self
-                .join_dpad_moves(&num)
+        memo.get((num, count), |memo, (num, count)| {
+            self.join_dpad_moves(num)
                 .iter()
                 .map(|m| {
                     m.iter()
-                        .map(|m| self.solve_depth(m.to_string(), cache, count - 1))
+                        .map(|m| self.solve_depth(m.to_string(), memo, count - 1))
                         .min()
                         .unwrap_or(0)
                 })
-                .sum::<usize>(),
-        };
-
-        cache.insert((num, count), len);
-        len
+                .sum::<usize>()
+        })
     }
 }
 
-fn main() {
-    let input = "169A
-279A
-540A
-869A
-789A";
+pub struct Day21;
 
-    let map = Map::new();
+impl Solution for Day21 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let code = recognize(pair(unsigned::<usize>, char('A')));
+        parse::finish(input, lines(map(code, str::to_string)))
+    }
 
-    let res = input
-        .trim()
-        .lines()
-        .map(|l| map.calculate_complexity(l, 25))
-        .sum::<usize>();
+    fn part1(input: &Self::Input) -> usize {
+        let map = Map::new();
+        input
+            .iter()
+            .map(|l| map.solve(l) * l.trim_end_matches('A').parse::<usize>().unwrap())
+            .sum()
+    }
 
-    println!("{}", res);
+    fn part2(input: &Self::Input) -> usize {
+        let map = Map::new();
+        input.iter().map(|l| map.calculate_complexity(l, 25)).sum()
+    }
 }
 
 #[cfg(test)]
@@ -473,4 +489,19 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn example() {
+        let input = Day21::parse(
+            "029A
+980A
+179A
+456A
+379A",
+        )
+        .unwrap();
+
+        assert_eq!(126384, Day21::part1(&input));
+        assert_eq!(154115708116294, Day21::part2(&input));
+    }
 }
@@ -0,0 +1,95 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    error::ParseError,
+    parse::{self, lines, unsigned},
+    pool::Pool,
+    Solution,
+};
+
+fn gen(s: i64) -> i64 {
+    let s = (s ^ s << 6) & 0xffffff;
+    let s = (s ^ s >> 5) & 0xffffff;
+    (s ^ s << 11) & 0xffffff
+}
+
+/// The secret `c` steps on from `s`. Each buyer's secrets are only ever
+/// visited once, so there is nothing worth caching.
+fn gen_times(s: i64, c: usize) -> i64 {
+    (0..c).fold(s, |s, _| gen(s))
+}
+
+fn most_bananas(is: &[i64]) -> i64 {
+    let subsq_count = Pool::default().reduce(
+        is,
+        HashMap::<(i64, i64, i64, i64), i64>::new,
+        |mut subsq_count, &l| {
+            let mut u = gen(l);
+            let mut sq = VecDeque::new();
+            let mut subsq = HashSet::<(i64, i64, i64, i64)>::new();
+
+            for _ in 1..2000 {
+                let u1 = gen(u);
+                sq.push_back(u1 % 10 - u % 10);
+                u = u1;
+                if sq.len() >= 4 {
+                    if subsq.insert((sq[0], sq[1], sq[2], sq[3])) {
+                        *subsq_count.entry((sq[0], sq[1], sq[2], sq[3])).or_insert(0) += u1 % 10;
+                    }
+                    sq.pop_front();
+                }
+            }
+
+            subsq_count
+        },
+        |mut a, b| {
+            for (k, v) in b {
+                *a.entry(k).or_insert(0) += v;
+            }
+            a
+        },
+    );
+
+    *subsq_count.values().max().unwrap()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::finish(input, lines(unsigned))
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        input.iter().map(|&s| gen_times(s, 2000)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        most_bananas(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day22::parse("1\n10\n100\n2024").unwrap();
+        assert_eq!(37327623, Day22::part1(&input));
+
+        let input = Day22::parse("1\n2\n3\n2024").unwrap();
+        assert_eq!(23, Day22::part2(&input));
+    }
+
+    #[test]
+    fn secrets() {
+        assert_eq!(123, gen_times(123, 0));
+        assert_eq!(1553684, gen_times(123, 5));
+        assert_eq!(5908254, gen_times(123, 10));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use nom::{
+    character::complete::{alpha1, char},
+    sequence::separated_pair,
+};
+
+use crate::{
+    error::ParseError,
+    parse::{self, lines},
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct Graph {
+    am: HashMap<usize, Vec<usize>>,
+}
+
+impl Graph {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut edges = HashSet::new();
+
+        for (v1, v2) in parse::finish(input, lines(separated_pair(alpha1, char('-'), alpha1)))? {
+            let id1 = v1.chars().fold(0, |acc, cur| acc * 256 + cur as usize);
+            let id2 = v2.chars().fold(0, |acc, cur| acc * 256 + cur as usize);
+
+            edges.extend([(id1, id2), (id2, id1)]);
+        }
+
+        let mut am = HashMap::new();
+
+        for &edge in edges.iter().sorted() {
+            am.entry(edge.0).or_insert_with(HashSet::new).insert(edge.1);
+            am.entry(edge.1).or_insert_with(HashSet::new).insert(edge.0);
+        }
+
+        let am = am
+            .iter()
+            .map(|(k, v)| {
+                let mut v = Vec::from_iter(v.iter().copied());
+                v.sort();
+                (*k, v)
+            })
+            .collect();
+
+        Ok(Self { am })
+    }
+
+    fn triangles_with(&self, prefix: char) -> usize {
+        let mut count = 0;
+
+        for (&v1, n1) in self.am.iter() {
+            for &v2 in n1.iter().filter(|&&v2| v2 > v1) {
+                for &v3 in self.am[&v2].iter().filter(|&&v3| v3 > v2) {
+                    if n1.contains(&v3) && [v1, v2, v3].iter().any(|&v| v >> 8 == prefix as usize) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    fn max_clique(&self) -> String {
+        let keys = self.am.keys().sorted().copied().collect::<Vec<_>>();
+
+        let best = self.max_clique_with(&[], &keys);
+
+        best.into_iter().map(to_str).join(",")
+    }
+
+    fn max_clique_with(&self, with: &[usize], rest: &[usize]) -> Vec<usize> {
+        let mut best = with.to_vec();
+
+        for &v in rest {
+            let mut with = with.to_vec();
+            with.push(v);
+
+            let rest = rest
+                .iter()
+                .filter(|&x| *x > v && self.am[&v].contains(x))
+                .copied()
+                .collect::<Vec<_>>();
+
+            let clique = self.max_clique_with(&with, &rest);
+
+            if clique.len() > best.len() {
+                best = clique;
+            }
+        }
+
+        best
+    }
+}
+
+fn to_str(w: usize) -> String {
+    [((w >> 8) & 0xff) as u8 as char, (w & 0xff) as u8 as char]
+        .into_iter()
+        .collect()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Graph::from_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.triangles_with('t')
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        input.max_clique()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day23::parse(
+            "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn",
+        )
+        .unwrap();
+
+        assert_eq!(7, Day23::part1(&input));
+        assert_eq!("co,de,ka,ta", Day23::part2(&input));
+    }
+}
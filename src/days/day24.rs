@@ -0,0 +1,216 @@
+use std::collections::{HashMap, VecDeque};
+
+use nom::{
+    branch::alt,
+    character::complete::{alphanumeric1, char, space0, space1},
+    combinator::{map, value},
+    sequence::{delimited, pair, preceded, separated_pair, tuple},
+};
+
+use crate::{
+    error::ParseError,
+    parse::{self, blank_lines, lines, lit},
+    Solution,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct Input {
+    reg: HashMap<usize, bool>,
+    program: Vec<(Op, usize, usize, usize)>,
+    queue: VecDeque<usize>,
+    pending: HashMap<usize, Vec<usize>>,
+}
+
+fn encode(z: &str) -> usize {
+    z.chars().fold(0, |acc, cur| (acc << 8) + cur as usize)
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    XOR,
+    OR,
+    AND,
+}
+
+impl Input {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let wire = || map(alphanumeric1, encode);
+        let bit = alt((value(false, char('0')), value(true, char('1'))));
+        let op = alt((
+            value(Op::AND, lit("AND")),
+            value(Op::OR, lit("OR")),
+            value(Op::XOR, lit("XOR")),
+        ));
+        let gate = map(
+            tuple((
+                wire(),
+                delimited(space1, op, space1),
+                wire(),
+                preceded(lit(" -> "), wire()),
+            )),
+            |(rs1, op, rs2, rd)| (op, rs1, rs2, rd),
+        );
+
+        let (initial, program): (Vec<_>, Vec<_>) = parse::finish(
+            input,
+            separated_pair(
+                lines(separated_pair(wire(), pair(char(':'), space0), bit)),
+                blank_lines,
+                lines(gate),
+            ),
+        )?;
+
+        let reg: HashMap<usize, bool> = initial.into_iter().collect();
+
+        Ok(Self {
+            reg,
+            queue: VecDeque::from_iter(0..program.len()),
+            program,
+            ..Default::default()
+        })
+    }
+
+    fn run(&mut self) -> usize {
+        self.queue = VecDeque::from_iter(0..self.program.len());
+        while let Some(line) = self.queue.pop_front() {
+            self.run_program(line);
+        }
+
+        let z = (0..)
+            .try_fold((0, 0), |acc, cur| {
+                let zk = format!("z{:02}", cur);
+                if let Some(&zv) = self.reg.get(&(encode(&zk))) {
+                    let zv = zv as usize;
+                    Ok((acc.0 + (zv << acc.1), acc.1 + 1))
+                } else {
+                    Err(acc)
+                }
+            })
+            .unwrap_err()
+            .0;
+
+        z
+    }
+
+    fn run_program(&mut self, line: usize) {
+        let p = self.program.get(line).unwrap();
+        let res = match (self.reg.get(&p.1), self.reg.get(&p.2)) {
+            (Some(&rs1), Some(&rs2)) => {
+                self.pending.remove(&p.2);
+                self.pending.remove(&p.1);
+
+                Some(match p.0 {
+                    Op::XOR => rs1 ^ rs2,
+                    Op::AND => rs1 & rs2,
+                    Op::OR => rs1 | rs2,
+                })
+            }
+            (Some(_), None) => {
+                self.pending.entry(p.2).or_default().push(line);
+                None
+            }
+            (None, Some(_)) => {
+                self.pending.entry(p.1).or_default().push(line);
+
+                None
+            }
+            _ => {
+                self.pending.entry(p.2).or_default().push(line);
+                self.pending.entry(p.1).or_default().push(line);
+
+                None
+            }
+        };
+
+        if let Some(res) = res {
+            self.reg.insert(p.3, res);
+            if let Some(pendings) = self.pending.get(&p.3) {
+                for &p in pendings.iter().rev() {
+                    self.queue.push_front(p);
+                }
+            }
+        }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::from_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.clone().run()
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "unsolved"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day24::parse(
+            "x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj",
+        )
+        .unwrap();
+
+        assert_eq!(2024, Day24::part1(&input));
+    }
+}
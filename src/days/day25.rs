@@ -0,0 +1,153 @@
+use crate::{
+    error::ParseError,
+    parse::{self, blocks, Error, PResult},
+    Solution,
+};
+
+#[derive(Debug)]
+pub enum Schema {
+    Key([i32; 5]),
+    Lock([i32; 5]),
+}
+
+impl Schema {
+    fn parse(input: &str) -> PResult<'_, Schema> {
+        let (rest, pins) = parse::grid("`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })(input)?;
+
+        if (pins.nrow(), pins.ncol()) != (7, 5) {
+            return Error::fail(input.trim_start(), "a 7 by 5 schematic");
+        }
+
+        let is_lock = pins.row(0).iter().all(|&p| p);
+        let mut c = [0; 5];
+        for (i, col) in pins.cols().enumerate() {
+            c[i] = col.skip(1).take(5).filter(|&&p| p).count() as i32;
+        }
+
+        Ok((
+            rest,
+            if is_lock {
+                Schema::Lock(c)
+            } else {
+                Schema::Key(c)
+            },
+        ))
+    }
+
+    fn is_key(&self) -> bool {
+        matches!(self, Schema::Key(_))
+    }
+
+    fn fit(&self, other: &Schema) -> bool {
+        let (key, lock) = if self.is_key() {
+            if other.is_key() {
+                return false;
+            }
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        match (key, lock) {
+            (Schema::Key(k), Schema::Lock(l)) => {
+                for i in 0..5 {
+                    if k[i] + l[i] > 5 {
+                        return false;
+                    }
+                }
+                true
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Schema>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::finish(input, blocks(Schema::parse))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let keys = input.iter().filter(|s| s.is_key()).collect::<Vec<_>>();
+        let locks = input.iter().filter(|s| !s.is_key()).collect::<Vec<_>>();
+
+        let mut count = 0;
+        for key in keys.iter() {
+            for lock in locks.iter() {
+                if key.fit(lock) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "Merry Christmas"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day25::parse(
+            "#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####",
+        )
+        .unwrap();
+
+        assert_eq!(3, Day25::part1(&input));
+        assert_eq!("Merry Christmas", Day25::part2(&input));
+    }
+}
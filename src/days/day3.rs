@@ -0,0 +1,580 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::{self, BufRead},
+    ops::RangeInclusive,
+};
+
+use itertools::Itertools;
+
+use crate::{error::ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Trace;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        InstructionSet::puzzle().run(input).map_err(|e| {
+            ParseError::at(
+                input,
+                &input[e.offset..],
+                "products that add up within 64 bits",
+            )
+        })
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        input.total("mul").all
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        input.total("mul").enabled
+    }
+}
+
+/// How far into an instruction the scanner is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Start,
+    M,
+    Mu,
+    Mul,
+    /// The arguments of `mul(` so far, `None` before their first digit.
+    First(Option<i32>),
+    Second(i32, Option<i32>),
+    D,
+    Do,
+    DoOpen,
+    Don,
+    DonQuote,
+    DonT,
+    DontOpen,
+}
+
+/// `n` with the digit `b` appended, if that still fits.
+fn digit(n: Option<i32>, b: u8) -> Option<i32> {
+    n.unwrap_or(0)
+        .checked_mul(10)?
+        .checked_add((b - b'0') as i32)
+}
+
+impl State {
+    /// The state after `b`, and the instruction it completes, or `None` if
+    /// `b` cannot continue the instruction.
+    fn next(self, b: u8) -> Option<(State, Option<Action>)> {
+        use State::*;
+
+        let next = match (self, b) {
+            (Start, b'm') => M,
+            (Start, b'd') => D,
+            (Start, _) => Start,
+            (M, b'u') => Mu,
+            (Mu, b'l') => Mul,
+            (Mul, b'(') => First(None),
+            (First(a), b'0'..=b'9') => First(Some(digit(a, b)?)),
+            (First(Some(a)), b',') => Second(a, None),
+            (Second(a, x), b'0'..=b'9') => Second(a, Some(digit(x, b)?)),
+            (Second(a, Some(x)), b')') => return Some((Start, Some(Action::Mul(a, x)))),
+            (D, b'o') => Do,
+            (Do, b'(') => DoOpen,
+            (DoOpen, b')') => return Some((Start, Some(Action::Do))),
+            (Do, b'n') => Don,
+            (Don, b'\'') => DonQuote,
+            (DonQuote, b't') => DonT,
+            (DonT, b'(') => DontOpen,
+            (DontOpen, b')') => return Some((Start, Some(Action::Dont))),
+            _ => return None,
+        };
+
+        Some((next, None))
+    }
+
+    /// Takes in `b`. A byte that breaks off an instruction may still start
+    /// the next one. No instruction can start partway into another, so the
+    /// scanner never has to back up.
+    fn step(&mut self, b: u8) -> Option<Action> {
+        let (next, action) = self
+            .next(b)
+            .or_else(|| State::Start.next(b))
+            .expect("any byte can follow the start");
+        *self = next;
+        action
+    }
+}
+
+/// The instructions in memory read from `reader`, found in one pass with
+/// only the instruction in progress kept.
+pub struct Scanner<R> {
+    reader: R,
+    state: State,
+}
+
+impl<R: BufRead> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            state: State::Start,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Scanner<R> {
+    type Item = io::Result<Action>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            };
+            if buf.is_empty() {
+                return None;
+            }
+
+            let mut used = 0;
+            let mut found = None;
+            for &b in buf {
+                used += 1;
+                found = self.state.step(b);
+                if found.is_some() {
+                    break;
+                }
+            }
+            self.reader.consume(used);
+
+            if let Some(action) = found {
+                return Some(Ok(action));
+            }
+        }
+    }
+}
+
+/// What an instruction does when it runs.
+#[derive(Debug, Clone, Copy)]
+pub enum Op {
+    /// Folds the arguments into one number and adds it to the named
+    /// accumulator. The fold gives `None` if it overflows.
+    Accumulate {
+        into: &'static str,
+        fold: fn(&[i64]) -> Option<i64>,
+    },
+    Enable,
+    Disable,
+}
+
+/// An instruction as it appears in memory: `name(a,b,...)` with unsigned
+/// arguments that fit an `i32`, as in the puzzle, and nothing else between
+/// the parentheses.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pub name: &'static str,
+    /// How many arguments it takes.
+    pub arity: RangeInclusive<usize>,
+    pub op: Op,
+}
+
+impl Pattern {
+    pub fn accumulate(
+        name: &'static str,
+        arity: RangeInclusive<usize>,
+        into: &'static str,
+        fold: fn(&[i64]) -> Option<i64>,
+    ) -> Self {
+        Self {
+            name,
+            arity,
+            op: Op::Accumulate { into, fold },
+        }
+    }
+
+    /// An instruction that switches accumulating back on.
+    pub fn enable(name: &'static str) -> Self {
+        Self {
+            name,
+            arity: 0..=0,
+            op: Op::Enable,
+        }
+    }
+
+    /// An instruction that switches accumulating off.
+    pub fn disable(name: &'static str) -> Self {
+        Self {
+            name,
+            arity: 0..=0,
+            op: Op::Disable,
+        }
+    }
+
+    /// The arguments and length of this instruction at the start of
+    /// `memory`.
+    fn matches(&self, memory: &str) -> Option<(Vec<i64>, usize)> {
+        let args = memory.strip_prefix(self.name)?.strip_prefix('(')?;
+        let len = args.find(|c: char| !(c.is_ascii_digit() || c == ','))?;
+        if !args[len..].starts_with(')') {
+            return None;
+        }
+
+        let args = match &args[..len] {
+            "" => vec![],
+            list => list
+                .split(',')
+                .map(|a| a.parse::<i32>().ok().map(i64::from))
+                .collect::<Option<Vec<_>>>()?,
+        };
+        self.arity
+            .contains(&args.len())
+            .then_some((args, self.name.len() + len + 2))
+    }
+}
+
+pub fn sum(args: &[i64]) -> Option<i64> {
+    args.iter().try_fold(0i64, |total, &a| total.checked_add(a))
+}
+
+/// The first argument less the rest.
+pub fn difference(args: &[i64]) -> Option<i64> {
+    args.split_first().map_or(Some(0), |(&first, rest)| {
+        rest.iter()
+            .try_fold(first, |total, &a| total.checked_sub(a))
+    })
+}
+
+pub fn product(args: &[i64]) -> Option<i64> {
+    args.iter().try_fold(1i64, |total, &a| total.checked_mul(a))
+}
+
+/// An instruction whose value, or the total it went into, does not fit an
+/// `i64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// Byte offset of the start of the instruction.
+    pub offset: usize,
+    pub name: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte {} overflows", self.name, self.offset)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Instructions to look for in corrupted memory. Where several match at the
+/// same place the first added wins.
+#[derive(Debug, Clone, Default)]
+pub struct InstructionSet {
+    patterns: Vec<Pattern>,
+}
+
+/// One instruction found in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Executed {
+    /// Byte offset of the start of the instruction.
+    pub offset: usize,
+    pub name: &'static str,
+    pub args: Vec<i64>,
+    /// Whether accumulating was on once it had run, so `don't()` itself is
+    /// disabled.
+    pub enabled: bool,
+}
+
+/// An accumulator's total over every instruction, and over those that ran
+/// while enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Total {
+    pub all: i64,
+    pub enabled: i64,
+}
+
+/// Everything a run of the interpreter did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub executed: Vec<Executed>,
+    pub totals: BTreeMap<&'static str, Total>,
+}
+
+impl Trace {
+    /// The total of `accumulator`, zero if nothing went into it.
+    pub fn total(&self, accumulator: &str) -> Total {
+        self.totals.get(accumulator).copied().unwrap_or_default()
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for e in self.executed.iter() {
+            writeln!(
+                f,
+                "{:>6} {}({}){}",
+                e.offset,
+                e.name,
+                e.args.iter().join(","),
+                if e.enabled { "" } else { " disabled" }
+            )?;
+        }
+        for (name, total) in self.totals.iter() {
+            writeln!(f, "{name}: {} ({} enabled)", total.all, total.enabled)?;
+        }
+        Ok(())
+    }
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The puzzle's `mul(a,b)`, `do()` and `don't()`, adding into `mul`.
+    pub fn puzzle() -> Self {
+        Self::new()
+            .with(Pattern::accumulate("mul", 2..=2, "mul", product))
+            .with(Pattern::enable("do"))
+            .with(Pattern::disable("don't"))
+    }
+
+    pub fn with(mut self, pattern: Pattern) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Runs every instruction in `memory` in one pass, skipping whatever
+    /// does not match. Instructions do not overlap. Stops at the first one
+    /// that overflows.
+    pub fn run(&self, memory: &str) -> Result<Trace, Overflow> {
+        let mut trace = Trace::default();
+        let mut enabled = true;
+        let mut at = 0;
+
+        while let Some(c) = memory[at..].chars().next() {
+            let found = self
+                .patterns
+                .iter()
+                .find_map(|p| p.matches(&memory[at..]).map(|m| (p, m)));
+            let Some((pattern, (args, len))) = found else {
+                at += c.len_utf8();
+                continue;
+            };
+
+            match pattern.op {
+                Op::Accumulate { into, fold } => {
+                    let overflow = || Overflow {
+                        offset: at,
+                        name: pattern.name,
+                    };
+                    let value = fold(&args).ok_or_else(overflow)?;
+                    let total = trace.totals.entry(into).or_default();
+                    total.all = total.all.checked_add(value).ok_or_else(overflow)?;
+                    if enabled {
+                        total.enabled = total.enabled.checked_add(value).ok_or_else(overflow)?;
+                    }
+                }
+                Op::Enable => enabled = true,
+                Op::Disable => enabled = false,
+            }
+
+            trace.executed.push(Executed {
+                offset: at,
+                name: pattern.name,
+                args,
+                enabled,
+            });
+            at += len;
+        }
+
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input =
+            Day3::parse("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))")
+                .unwrap();
+        assert_eq!(161, Day3::part1(&input));
+
+        let input = Day3::parse(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        )
+        .unwrap();
+        assert_eq!(161, Day3::part1(&input));
+        assert_eq!(48, Day3::part2(&input));
+
+        let input = Day3::parse("mul(2,4)").unwrap();
+        assert_eq!(8, Day3::part1(&input));
+    }
+
+    #[test]
+    fn interprets() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let trace = InstructionSet::puzzle().run(memory).unwrap();
+
+        assert_eq!(
+            Total {
+                all: 161,
+                enabled: 48
+            },
+            trace.total("mul")
+        );
+        assert_eq!(
+            "     1 mul(2,4)
+    20 don't() disabled
+    28 mul(5,5) disabled
+    48 mul(11,8) disabled
+    59 do()
+    64 mul(8,5)
+mul: 161 (48 enabled)
+",
+            trace.to_string()
+        );
+    }
+
+    #[test]
+    fn extended() {
+        let set = InstructionSet::new()
+            .with(Pattern::accumulate("add", 1..=usize::MAX, "sum", sum))
+            .with(Pattern::accumulate(
+                "sub",
+                1..=usize::MAX,
+                "sum",
+                difference,
+            ))
+            .with(Pattern::accumulate("mul", 2..=3, "product", product))
+            .with(Pattern::disable("stop"))
+            .with(Pattern::enable("go"));
+        let trace = set
+            .run("add(1,2,3)sub(10,4)mul(2)mul(2,3,4)stop()add(5)mul(1,1)go()sub(1)")
+            .unwrap();
+
+        assert_eq!(
+            Total {
+                all: 6 + 6 + 5 + 1,
+                enabled: 6 + 6 + 1
+            },
+            trace.total("sum")
+        );
+        assert_eq!(
+            Total {
+                all: 24 + 1,
+                enabled: 24
+            },
+            trace.total("product")
+        );
+        assert_eq!(Total::default(), trace.total("nothing"));
+        assert_eq!(
+            vec![0, 10, 25, 35, 41, 47, 55, 59],
+            trace.executed.iter().map(|e| e.offset).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn overflows() {
+        let max = i32::MAX;
+        let puzzle = InstructionSet::puzzle();
+        let trace = puzzle
+            .run(&format!("mul({max},{max})mul(2147483648,1)"))
+            .unwrap();
+        assert_eq!(1, trace.executed.len());
+
+        // each fits, but not all three added up
+        let memory = format!("mul({max},{max})").repeat(3);
+        assert_eq!(
+            Err(Overflow {
+                offset: 52,
+                name: "mul"
+            }),
+            puzzle.run(&memory)
+        );
+        assert_eq!(
+            "line 1, column 53: expected products that add up within 64 bits, found `mul(2147483647,2147483647)`",
+            Day3::parse(&memory).unwrap_err().to_string()
+        );
+        let input = Day3::parse(&format!("mul({max},2)")).unwrap();
+        assert_eq!(2 * max as i64, Day3::part1(&input));
+
+        let set = InstructionSet::new().with(Pattern::accumulate("mul", 3..=3, "mul", product));
+        assert_eq!(
+            "mul at byte 1 overflows",
+            set.run(&format!("xmul({max},{max},{max})"))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn scanner_matches_interpreter() {
+        use crate::gen::{self, Rng};
+        use std::io::BufReader;
+
+        const PIECES: &[&str] = &[
+            "mul(",
+            "do()",
+            "don't()",
+            "mul",
+            "do",
+            "don't",
+            "(",
+            ")",
+            ",",
+            "1",
+            "23",
+            "999",
+            "2147483647",
+            "2147483648",
+            "x",
+            " ",
+            "m",
+            "d",
+            "'",
+            "n",
+            "t",
+            "é",
+        ];
+        let mut rng = Rng::new(3);
+        let mut memories = (0..5)
+            .map(|seed| gen::generate(3, seed, Some(100)).unwrap())
+            .collect::<Vec<_>>();
+        memories.extend((0..300).map(|_| {
+            (0..rng.below(40))
+                .map(|_| *rng.pick(PIECES))
+                .collect::<String>()
+        }));
+
+        for memory in memories {
+            let expected = InstructionSet::puzzle()
+                .run(&memory)
+                .unwrap()
+                .executed
+                .into_iter()
+                .map(|e| match (e.name, &e.args[..]) {
+                    ("mul", &[a, b]) => Action::Mul(a as i32, b as i32),
+                    ("do", _) => Action::Do,
+                    _ => Action::Dont,
+                })
+                .collect_vec();
+            for capacity in [1, 3, 4096] {
+                let reader = BufReader::with_capacity(capacity, memory.as_bytes());
+                let scanned = Scanner::new(reader)
+                    .collect::<io::Result<Vec<_>>>()
+                    .unwrap();
+                assert_eq!(expected, scanned, "{memory:?} read {capacity} at a time");
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Debug;
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    pos::{Dir, Pos},
+    Solution,
+};
+
+pub struct Puzzle {
+    grid: Grid<char>,
+}
+
+impl Puzzle {
+    fn match_for(&self, pattern: &str, from: Pos, direction: Dir) -> bool {
+        let mut pos = Some(from);
+
+        for c in pattern.chars() {
+            match pos {
+                Some(p) if self.grid[p] == c => pos = self.grid.offset(p, direction),
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    fn count_match(&self, pattern: &str) -> usize {
+        let mut count = 0;
+        for pos in self.grid.positions() {
+            for dir in Dir::COMPASS {
+                if self.match_for(pattern, pos, dir) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    fn count_x(&self) -> usize {
+        let mut count = 0;
+        for pos in self.grid.positions() {
+            if (self.match_for("AM", pos, Dir::NW) && self.match_for("AS", pos, Dir::SE)
+                || self.match_for("AS", pos, Dir::NW) && self.match_for("AM", pos, Dir::SE))
+                && (self.match_for("AS", pos, Dir::NE) && self.match_for("AM", pos, Dir::SW)
+                    || self.match_for("AM", pos, Dir::NE) && self.match_for("AS", pos, Dir::SW))
+            {
+                count += 1
+            }
+        }
+
+        count
+    }
+}
+
+impl Debug for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            for c in row {
+                write!(f, "{} ", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_puzzle(input: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle {
+        grid: Grid::parse(input, |c| c)?,
+    })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_puzzle(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.count_match("XMAS")
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.count_x()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn sample() {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM  
+MXMXAXMASX";
+
+        let pz = parse_puzzle(input).unwrap();
+        println!("{:?}", pz);
+
+        println!("{:?}", pz.match_for("XMAS", Pos::new(0, 4), Dir::SE));
+        assert_eq!(18, pz.count_match("XMAS"));
+    }
+
+    #[test]
+    fn sample2() {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM  
+MXMXAXMASX";
+
+        let pz = parse_puzzle(input).unwrap();
+        println!("{:?}", pz);
+
+        assert_eq!(9, pz.count_x());
+    }
+
+    #[test]
+    fn try_running() {
+        println!("{:?}", [-1, 1].iter().zip([-1, 1]).collect::<Vec<_>>())
+    }
+}
@@ -1,8 +1,14 @@
 use std::{collections::HashSet, fmt::Debug};
 
-use aoc_2024::read_input_v1;
+use nom::{character::complete::char, sequence::separated_pair};
 
-struct Input {
+use crate::{
+    error::ParseError,
+    parse::{self, blank_lines, comma_list, lines, unsigned},
+    Solution,
+};
+
+pub struct Input {
     rules: Vec<(usize, usize)>,
     updates: Vec<Vec<usize>>,
 }
@@ -79,10 +85,7 @@ impl Debug for Input {
     }
 }
 
-#[allow(dead_code)]
-fn solve(input: &str) -> usize {
-    let parsed = parse_input(input);
-
+fn solve(parsed: &Input) -> usize {
     let rules = Rule::from_vec(&parsed.rules);
 
     let passed = parsed
@@ -101,9 +104,7 @@ fn solve(input: &str) -> usize {
     passed
 }
 
-fn solve2(input: &str) -> usize {
-    let parsed = parse_input(input);
-
+fn solve2(parsed: &Input) -> usize {
     let rules = Rule::from_vec(&parsed.rules);
 
     let passed = parsed
@@ -118,39 +119,37 @@ fn solve2(input: &str) -> usize {
 
     passed
 }
-fn parse_input(input: &str) -> Input {
-    let (r, u) = input.split_once("\n\n").unwrap();
-
-    let rules = r
-        .lines()
-        .map(|p| {
-            let (n1, n2) = p.split_once("|").unwrap();
-            let n1 = n1.parse::<usize>().unwrap();
-            let n2 = n2.parse::<usize>().unwrap();
-            (n1, n2)
-        })
-        .collect::<Vec<_>>();
-
-    let updates = u
-        .lines()
-        .map(|p| {
-            p.split(",")
-                .map(|p| p.parse::<usize>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    Input { rules, updates }
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let rule = separated_pair(unsigned, char('|'), unsigned);
+    let (rules, updates) = parse::finish(
+        input,
+        separated_pair(lines(rule), blank_lines, lines(comma_list(unsigned))),
+    )?;
+
+    Ok(Input { rules, updates })
 }
-fn main() {
-    let input = &read_input_v1(5);
-    println!("{}", solve2(input));
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        solve2(input)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::solve2;
-
     use super::*;
 
     #[test]
@@ -184,7 +183,7 @@ mod test {
 61,13,29
 97,13,75,29,47";
 
-        let parsed = parse_input(input);
+        let parsed = parse_input(input).unwrap();
 
         let rules = Rule::from_vec(&parsed.rules);
 
@@ -201,9 +200,7 @@ mod test {
             .map(|u| u.unwrap())
             .sum::<usize>();
 
-        // println!("{:?}", parsed);
-        // println!("{:?}", rules);
-        println!("{passed:?}");
+        assert_eq!(143, passed);
     }
 
     #[test]
@@ -237,7 +234,6 @@ mod test {
 61,13,29
 97,13,75,29,47";
 
-        let parsed = solve2(input);
-        println!("{parsed:?}");
+        assert_eq!(123, solve2(&parse_input(input).unwrap()));
     }
 }
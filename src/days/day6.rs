@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::ParseError,
+    grid::Grid,
+    pool::Pool,
+    pos::{Dir, Pos},
+    viz::{Animation, Colour, Frame, Mark},
+    Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct State {
+    guard_pos: Pos,
+    guard_dir: Dir,
+    walls: Grid<bool>,
+}
+
+impl State {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(input, "`#`, `.` or a guard", |c| match c {
+            '#' | '.' => Some(c),
+            c => Dir::from_arrow(c).map(|_| c),
+        })?;
+
+        let (guard_pos, guard_dir) = grid
+            .iter()
+            .find_map(|(p, &c)| Dir::from_arrow(c).map(|d| (p, d)))
+            .ok_or_else(|| ParseError::after(input, input.trim_end(), "a guard"))?;
+        let walls = grid.map(|&c| c == '#');
+
+        Ok(Self {
+            guard_dir,
+            guard_pos,
+            walls,
+        })
+    }
+
+    fn walk(&self, wall: Option<Pos>) -> (HashMap<Pos, HashSet<Dir>>, bool) {
+        let mut walking_set = HashMap::new();
+        let mut was_loop = false;
+        let mut pos = self.guard_pos;
+        let mut dir = self.guard_dir;
+
+        loop {
+            walking_set
+                .entry(pos)
+                .or_insert_with(HashSet::new)
+                .insert(dir);
+
+            let Some(next) = self.walls.offset(pos, dir) else {
+                break;
+            };
+
+            if self.walls[next] || wall == Some(next) {
+                dir = dir.turn_right();
+                continue;
+            }
+
+            pos = next;
+
+            if let Some(dir_set) = walking_set.get(&pos) {
+                if dir_set.contains(&dir) {
+                    was_loop = true;
+                    break;
+                }
+            }
+        }
+
+        (walking_set, was_loop)
+    }
+
+    /// Every position and heading of the guard, one step or turn at a time,
+    /// until they leave the map or start going round in circles.
+    fn route(&self) -> Vec<(Pos, Dir)> {
+        let mut route = vec![(self.guard_pos, self.guard_dir)];
+        let mut seen = HashSet::from([route[0]]);
+
+        while let Some(&(pos, dir)) = route.last() {
+            let Some(next) = self.walls.offset(pos, dir) else {
+                break;
+            };
+
+            let state = if self.walls[next] {
+                (pos, dir.turn_right())
+            } else {
+                (next, dir)
+            };
+            if !seen.insert(state) {
+                break;
+            }
+            route.push(state);
+        }
+
+        route
+    }
+}
+
+struct Patrol {
+    walls: Grid<bool>,
+    route: Vec<(Pos, Dir)>,
+}
+
+impl Animation for Patrol {
+    fn frames(&self) -> usize {
+        self.route.len()
+    }
+
+    fn frame(&mut self, i: usize) -> Frame {
+        let mut grid = self.walls.map(|&w| if w { '#' } else { '.' });
+        for &(pos, _) in self.route[..=i].iter() {
+            grid[pos] = 'X';
+        }
+        let visited = grid.find_all(&'X').count();
+
+        let (pos, dir) = self.route[i];
+        Frame::new(grid)
+            .palette(&[('X', Colour::Yellow)])
+            .marks([Mark::new(pos, dir.arrow().unwrap(), Some(Colour::Red))])
+            .caption(format!("{visited} positions visited"))
+    }
+}
+
+/// The guard's patrol, one step or turn per frame.
+pub fn animate(input: &str) -> Result<Box<dyn Animation>, ParseError> {
+    let state = State::from_input(input)?;
+    Ok(Box::new(Patrol {
+        route: state.route(),
+        walls: state.walls,
+    }))
+}
+
+fn solve1(state: &State) -> usize {
+    let (result, _) = state.walk(None);
+    result.len()
+}
+
+fn solve2(state: &State) -> usize {
+    let (all, _) = state.walk(None);
+    let candidates = all
+        .into_keys()
+        .filter(|&p| p != state.guard_pos)
+        .collect::<Vec<_>>();
+
+    Pool::default().reduce(
+        &candidates,
+        || 0,
+        |count, &p| count + usize::from(state.walk(Some(p)).1),
+        |a, b| a + b,
+    )
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = State;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        State::from_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        solve1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let example = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let input = Day6::parse(example).unwrap();
+
+        assert_eq!(41, Day6::part1(&input));
+        assert_eq!(6, Day6::part2(&input));
+
+        let mut patrol = animate(example).unwrap();
+        let last = patrol.frame(patrol.frames() - 1);
+        assert_eq!("41 positions visited", last.caption);
+    }
+}
@@ -0,0 +1,141 @@
+use nom::{
+    character::complete::{char, space0},
+    sequence::{separated_pair, tuple},
+};
+
+use crate::{
+    error::ParseError,
+    parse::{self, lines, spaced, unsigned},
+    pool::Pool,
+    Solution,
+};
+
+#[derive(Clone)]
+struct Equation {
+    target: usize,
+    elements: Vec<usize>,
+}
+
+impl Equation {
+    fn check(&self, concat: bool) -> Option<String> {
+        let mut stack = (0..self.elements.len())
+            .map(|idx| {
+                let mut elements = self.elements.clone();
+                elements.reverse();
+                let first = elements[idx];
+                elements.remove(idx);
+                (first, elements, format!("{first}"))
+            })
+            .collect::<Vec<_>>();
+        while let Some((curr, mut rest, str)) = stack.pop() {
+            match rest.pop() {
+                None => {
+                    if rest.is_empty() {
+                        if curr == self.target {
+                            return Some(str);
+                        }
+                        continue;
+                    }
+
+                    if curr >= self.target {
+                        continue;
+                    }
+                }
+                Some(next) => {
+                    let sum = curr + next;
+                    let prod = curr * next;
+
+                    if sum <= self.target {
+                        stack.push((sum, rest.clone(), format!("{str} + {next}")));
+                    }
+
+                    if prod <= self.target {
+                        stack.push((prod, rest.clone(), format!("{str} * {next}")));
+                    }
+
+                    if concat {
+                        let concat = format!("{curr}{next}").parse::<usize>().unwrap();
+                        if concat <= self.target {
+                            stack.push((concat, rest, format!("{str} || {next}")));
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+pub struct Input {
+    eqs: Vec<Equation>,
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let equation = separated_pair(unsigned, tuple((char(':'), space0)), spaced(unsigned));
+
+        Ok(Self {
+            eqs: parse::finish(input, lines(equation))?
+                .into_iter()
+                .map(|(target, elements)| Equation { target, elements })
+                .collect(),
+        })
+    }
+}
+
+fn calibrate(eqs: &[Equation], concat: bool) -> usize {
+    Pool::default().reduce(
+        eqs,
+        || 0,
+        |sum, eq| match eq.check(concat) {
+            Some(_) => sum + eq.target,
+            None => sum,
+        },
+        |a, b| a + b,
+    )
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        calibrate(&input.eqs, false)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        calibrate(&input.eqs, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day7::parse(
+            "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap();
+
+        assert_eq!(3749, Day7::part1(&input));
+        assert_eq!(11387, Day7::part2(&input));
+    }
+}
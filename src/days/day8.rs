@@ -0,0 +1,136 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::{error::ParseError, grid::Grid, Solution};
+
+type Coord = (isize, isize);
+
+#[derive(Debug)]
+pub struct Input {
+    ncol: usize,
+    nrow: usize,
+    antennas: HashMap<char, HashSet<Coord>>,
+}
+
+impl Input {
+    fn get_antinodes(&self, p1: Coord, p2: Coord) -> Vec<Coord> {
+        let mut res = vec![];
+        let d = (p2.0 - p1.0, p2.1 - p1.1);
+        let n1 = (p2.0 + d.0, p2.1 + d.1);
+        let n2 = (p1.0 - d.0, p1.1 - d.1);
+
+        if n1.0 >= 0 && n1.1 >= 0 && n1.0 < self.nrow as isize && n1.1 < self.ncol as isize {
+            res.push(n1);
+        }
+
+        if n2.0 >= 0 && n2.1 >= 0 && n2.0 < self.nrow as isize && n2.1 < self.ncol as isize {
+            res.push(n2);
+        }
+
+        res
+    }
+
+    fn get_antinodes_with_resonance(&self, p1: Coord, p2: Coord) -> Vec<Coord> {
+        let mut res = vec![];
+        let d = (p2.0 - p1.0, p2.1 - p1.1);
+        let mut n1 = p1;
+        let mut n2 = p2;
+
+        while n1.0 >= 0 && n1.1 >= 0 && n1.0 < self.nrow as isize && n1.1 < self.ncol as isize {
+            res.push(n1);
+            n1 = (n1.0 + d.0, n1.1 + d.1);
+        }
+
+        while n2.0 >= 0 && n2.1 >= 0 && n2.0 < self.nrow as isize && n2.1 < self.ncol as isize {
+            res.push(n2);
+            n2 = (n2.0 - d.0, n2.1 - d.1);
+        }
+
+        res
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(input, "an antenna or `.`", |c| {
+            (c == '.' || c.is_alphanumeric()).then_some(c)
+        })?;
+
+        let mut antennas: HashMap<char, HashSet<Coord>> = HashMap::new();
+        for (p, &c) in grid.iter().filter(|(_, c)| c.is_alphanumeric()) {
+            antennas
+                .entry(c)
+                .or_default()
+                .insert((p.row as isize, p.col as isize));
+        }
+
+        Ok(Self {
+            ncol: grid.ncol(),
+            nrow: grid.nrow(),
+            antennas,
+        })
+    }
+
+    fn antinodes(&self, resonance: bool) -> usize {
+        let mut nodes = HashSet::new();
+
+        for poses in self.antennas.values() {
+            for pv in poses.iter().combinations(2) {
+                let found = if resonance {
+                    self.get_antinodes_with_resonance(*pv[0], *pv[1])
+                } else {
+                    self.get_antinodes(*pv[0], *pv[1])
+                };
+
+                nodes.extend(found);
+            }
+        }
+
+        nodes.len()
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.antinodes(false)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.antinodes(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day8::parse(
+            "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+        )
+        .unwrap();
+
+        assert_eq!(14, Day8::part1(&input));
+        assert_eq!(34, Day8::part2(&input));
+    }
+}
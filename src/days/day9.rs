@@ -1,8 +1,9 @@
 use std::fmt::Debug;
 
-use aoc_2024::read_input_v1;
+use crate::{error::ParseError, Solution};
 
-struct Input {
+#[derive(Clone)]
+pub struct Input {
     blocks: Vec<Option<usize>>,
     bws: Vec<(usize, Option<usize>)>,
 }
@@ -31,15 +32,18 @@ impl Debug for Input {
 }
 
 impl Input {
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, ParseError> {
         let mut curr = 0;
         let mut is_empty = false;
 
         let mut blocks = vec![];
         let mut bws = vec![];
 
-        for block in input.chars() {
-            let size = block.to_digit(10).unwrap();
+        let disk = input.trim();
+        for (i, block) in disk.char_indices() {
+            let size = block
+                .to_digit(10)
+                .ok_or_else(|| ParseError::at(input, &disk[i..], "a digit"))?;
 
             for _ in 0..size {
                 if is_empty {
@@ -59,7 +63,7 @@ impl Input {
             }
         }
 
-        Self { blocks, bws }
+        Ok(Self { blocks, bws })
     }
 
     fn align(&mut self) {
@@ -129,12 +133,39 @@ impl Input {
     }
 }
 
-fn main() {
-    // let input = "2333133121414131402";
-    let input = &read_input_v1(9);
-    let mut p = Input::parse(input);
-    println!("{p:?}");
-    p.align_whole();
-    println!("{p:?}");
-    println!("{}", p.checksum_whole());
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let mut p = input.clone();
+        p.align();
+        p.checksum()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        let mut p = input.clone();
+        p.align_whole();
+        p.checksum_whole()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = Day9::parse("2333133121414131402").unwrap();
+
+        assert_eq!(1928, Day9::part1(&input));
+        assert_eq!(2858, Day9::part2(&input));
+    }
 }
@@ -0,0 +1,61 @@
+use crate::solution::{parser, Parser};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub static REGISTRY: &[(usize, Parser)] = &[
+    (1, parser::<day1::Day1>),
+    (2, parser::<day2::Day2>),
+    (3, parser::<day3::Day3>),
+    (4, parser::<day4::Day4>),
+    (5, parser::<day5::Day5>),
+    (6, parser::<day6::Day6>),
+    (7, parser::<day7::Day7>),
+    (8, parser::<day8::Day8>),
+    (9, parser::<day9::Day9>),
+    (10, parser::<day10::Day10>),
+    (11, parser::<day11::Day11>),
+    (12, parser::<day12::Day12>),
+    (13, parser::<day13::Day13>),
+    (14, parser::<day14::Day14>),
+    (15, parser::<day15::Day15>),
+    (16, parser::<day16::Day16>),
+    (17, parser::<day17::Day17>),
+    (18, parser::<day18::Day18>),
+    (19, parser::<day19::Day19>),
+    (20, parser::<day20::Day20>),
+    (21, parser::<day21::Day21>),
+    (22, parser::<day22::Day22>),
+    (23, parser::<day23::Day23>),
+    (24, parser::<day24::Day24>),
+    (25, parser::<day25::Day25>),
+];
+
+pub fn get(day: usize) -> Option<Parser> {
+    REGISTRY
+        .iter()
+        .find_map(|&(d, parser)| (d == day).then_some(parser))
+}
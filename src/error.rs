@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+/// Malformed puzzle input, located by 1-based line and column.
+///
+/// Parsers leave `day` at 0; the runner fills it in with [`ParseError::with_day`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: usize,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            day: 0,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error at `fragment`, which must be a slice of `input`; what was
+    /// found is read from there.
+    pub fn at(input: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        debug_assert!(
+            fragment.is_empty() || input.as_bytes()[offset..].starts_with(fragment.as_bytes()),
+            "fragment is not a slice of the input"
+        );
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |nl| &before[nl + 1..])
+            .chars()
+            .count()
+            + 1;
+
+        let found = match fragment.split_whitespace().next() {
+            Some(token) if fragment.starts_with(token) => format!("`{token}`"),
+            _ => match input[offset..].chars().next() {
+                None => "end of input".to_string(),
+                Some('\n' | '\r') => "end of line".to_string(),
+                Some(c) => format!("`{c}`"),
+            },
+        };
+
+        Self::new(line, column, expected, found)
+    }
+
+    /// An error just past the end of `fragment`, for input that stops short.
+    pub fn after(input: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &fragment[fragment.len()..], expected)
+    }
+
+    pub fn with_day(mut self, day: usize) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// The offending line of `input` with a caret under the column.
+    pub fn snippet(&self, input: &str) -> String {
+        let Some(line) = input.lines().nth(self.line.saturating_sub(1)) else {
+            return String::new();
+        };
+        let gutter = self.line.to_string().len();
+
+        format!(
+            "{:gutter$} |\n{} | {}\n{:gutter$} | {:>col$}",
+            "",
+            self.line,
+            line,
+            "",
+            "^",
+            col = self.column
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.day > 0 {
+            write!(f, "day {}, ", self.day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn locates_fragment() {
+        let input = "1 2\n3 x4\n";
+        let x = &input[6..8];
+
+        let err = ParseError::at(input, x, "a number").with_day(9);
+        assert_eq!(ParseError::new(2, 3, "a number", "`x4`").with_day(9), err);
+        assert_eq!(
+            "day 9, line 2, column 3: expected a number, found `x4`",
+            err.to_string()
+        );
+        assert_eq!("  |\n2 | 3 x4\n  |   ^", err.snippet(input));
+
+        let err = ParseError::after(input, &input[..3], "`,`");
+        assert_eq!(
+            (1, 4, "end of line"),
+            (err.line, err.column, err.found.as_str())
+        );
+        assert_eq!(
+            "end of input",
+            ParseError::at(input, &input[9..], "x").found
+        );
+    }
+}
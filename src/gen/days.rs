@@ -0,0 +1,598 @@
+use std::{collections::HashSet, fmt::Write};
+
+use itertools::Itertools;
+
+use super::Rng;
+use crate::{
+    grid::Grid,
+    pos::{Dir, Pos},
+    search,
+};
+
+/// `size` lines of two columns, the right often repeating the left.
+pub fn day1(rng: &mut Rng, size: usize) -> String {
+    let left = (0..size.max(1))
+        .map(|_| rng.between(10000, 99999))
+        .collect::<Vec<_>>();
+
+    left.iter()
+        .map(|&l| {
+            let r = if rng.chance(0.3) {
+                *rng.pick(&left)
+            } else {
+                rng.between(10000, 99999)
+            };
+            format!("{l}   {r}\n")
+        })
+        .collect()
+}
+
+/// `size` reports, about half of them safe and some one bad level away.
+pub fn day2(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let sign = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.between(20, 70);
+            let mut levels = vec![level];
+            for _ in 0..rng.between(4, 7) {
+                level += sign * rng.between(1, 3);
+                levels.push(level);
+            }
+
+            if rng.chance(0.5) {
+                let i = rng.below(levels.len());
+                levels[i] += rng.between(-4, 4);
+            }
+
+            levels.iter().join(" ") + "\n"
+        })
+        .collect()
+}
+
+/// `size` fragments of noise, `mul`s and `do`/`don't`s.
+pub fn day3(rng: &mut Rng, size: usize) -> String {
+    const NOISE: &[&str] = &[
+        "mul",
+        "(",
+        ")",
+        ",",
+        "[",
+        "]",
+        "%",
+        "@",
+        "!",
+        "where()",
+        "how()",
+        " ",
+        "'",
+        "mul ( 2,4)",
+        "mul(4*",
+        "mul(6,9!",
+        "?(12,34)",
+        "mul(1234,5)",
+    ];
+    let mut memory = String::new();
+
+    // noise alone may be blank, and blank input is rejected
+    while memory.trim().is_empty() {
+        memory.clear();
+        for _ in 0..size.max(1) {
+            match rng.below(10) {
+                0..=3 => {
+                    let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+                    write!(memory, "mul({a},{b})").unwrap();
+                }
+                4 => memory.push_str("do()"),
+                5 => memory.push_str("don't()"),
+                _ => memory.push_str(NOISE[rng.below(NOISE.len())]),
+            }
+        }
+    }
+
+    memory + "\n"
+}
+
+/// A `size` by `size` word search.
+pub fn day4(rng: &mut Rng, size: usize) -> String {
+    let letters = ['X', 'M', 'A', 'S'];
+    let size = size.max(1);
+    Grid::new(size, size, ' ')
+        .map(|_| *rng.pick(&letters))
+        .to_string()
+}
+
+/// Rules totally ordering a few dozen pages and `size` updates, half of
+/// them in order.
+pub fn day5(rng: &mut Rng, size: usize) -> String {
+    let mut pages = (10..100).collect::<Vec<_>>();
+    rng.shuffle(&mut pages);
+    pages.truncate(rng.between(20, 49) as usize);
+
+    let mut rules = pages.iter().tuple_combinations::<(_, _)>().collect_vec();
+    rng.shuffle(&mut rules);
+    let mut input = rules.iter().map(|(a, b)| format!("{a}|{b}\n")).join("");
+    input.push('\n');
+
+    for _ in 0..size.max(1) {
+        let len = 2 * rng.between(2, 11) as usize + 1;
+        let mut update = (0..pages.len()).collect_vec();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+        if rng.chance(0.5) {
+            update.sort();
+        }
+        writeln!(input, "{}", update.iter().map(|&i| pages[i]).join(",")).unwrap();
+    }
+
+    input
+}
+
+/// A `size` by `size` lab with scattered obstructions and one guard.
+pub fn day6(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut lab = Grid::new(size, size, '.').map(|_| if rng.chance(0.08) { '#' } else { '.' });
+    let guard = Pos::new(rng.below(size), rng.below(size));
+    lab[guard] = '^';
+    lab.to_string()
+}
+
+/// `size` equations, about half of which some operators satisfy.
+pub fn day7(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut lines = 0;
+
+    while lines < size.max(1) {
+        let elements = (0..rng.between(2, 8))
+            .map(|_| rng.between(1, 99) as u64)
+            .collect_vec();
+
+        let mut target = elements[0];
+        for &e in &elements[1..] {
+            target = match rng.below(3) {
+                0 => target + e,
+                1 => target * e,
+                _ => format!("{target}{e}").parse().unwrap(),
+            };
+        }
+        if rng.chance(0.5) {
+            target += rng.between(1, 9) as u64;
+        }
+
+        // keep clear of overflow when the solver concatenates
+        if target < 1 << 50 {
+            writeln!(input, "{target}: {}", elements.iter().join(" ")).unwrap();
+            lines += 1;
+        }
+    }
+
+    input
+}
+
+/// A `size` by `size` map with a few antennas per frequency.
+pub fn day8(rng: &mut Rng, size: usize) -> String {
+    let freqs = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect_vec();
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, '.');
+
+    for _ in 0..size * size / 25 {
+        map[Pos::new(rng.below(size), rng.below(size))] = *rng.pick(&freqs[..10]);
+    }
+    for &f in freqs.iter().skip(10).take(size / 2) {
+        for _ in 0..rng.between(2, 4) {
+            map[Pos::new(rng.below(size), rng.below(size))] = f;
+        }
+    }
+
+    map.to_string()
+}
+
+/// A disk map of `size` files.
+pub fn day9(rng: &mut Rng, size: usize) -> String {
+    let mut disk = String::new();
+
+    for i in 0..size.max(1) {
+        if i > 0 {
+            disk.push_str(&rng.between(0, 9).to_string());
+        }
+        disk.push_str(&rng.between(1, 9).to_string());
+    }
+
+    disk + "\n"
+}
+
+/// A `size` by `size` map whose heights mostly climb or drop by one from a
+/// neighbour, so trails run a long way.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, 0i64);
+
+    for p in map.positions().collect_vec() {
+        let near = [Dir::N, Dir::W]
+            .into_iter()
+            .filter_map(|d| map.offset(p, d))
+            .collect_vec();
+
+        map[p] = if near.is_empty() || rng.chance(0.1) {
+            rng.between(0, 9)
+        } else {
+            match map[*rng.pick(&near)] {
+                0 => 1,
+                9 => 8,
+                h if rng.chance(0.5) => h + 1,
+                h => h - 1,
+            }
+        };
+    }
+
+    map.to_string()
+}
+
+/// `size` stones.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| rng.between(0, 999999)).join(" ") + "\n"
+}
+
+/// A `size` by `size` garden of clumped plots.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    let plants = ('A'..='Z').collect_vec();
+    let size = size.max(1);
+    let mut garden = Grid::new(size, size, ' ');
+
+    for p in garden.positions().collect_vec() {
+        let near = [Dir::N, Dir::W]
+            .into_iter()
+            .filter_map(|d| garden.offset(p, d))
+            .collect_vec();
+
+        garden[p] = if near.is_empty() || rng.chance(0.3) {
+            *rng.pick(&plants[..8])
+        } else {
+            garden[*rng.pick(&near)]
+        };
+    }
+
+    garden.to_string()
+}
+
+/// `size` claw machines, about half of them winnable.
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let [ax, ay, bx, by] = [(); 4].map(|_| rng.between(10, 99));
+            let (tx, ty) = if rng.chance(0.5) {
+                let (a, b) = (rng.between(0, 100), rng.between(0, 100));
+                (a * ax + b * bx, a * ay + b * by)
+            } else {
+                (rng.between(1000, 20000), rng.between(1000, 20000))
+            };
+
+            format!("Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={tx}, Y={ty}\n")
+        })
+        .join("\n")
+}
+
+/// `size` robots, which all stand on different tiles at some random second.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    let (w, h) = (101, 103);
+    let mut tiles = (0..w * h).collect_vec();
+    rng.shuffle(&mut tiles);
+    let at = rng.between(0, w * h - 1);
+
+    tiles
+        .into_iter()
+        .take(size.clamp(2, (w * h) as usize))
+        .map(|tile| {
+            let (vx, vy) = (rng.between(-99, 99), rng.between(-99, 99));
+            let x = (tile % w - vx * at).rem_euclid(w);
+            let y = (tile / w - vy * at).rem_euclid(h);
+            format!("p={x},{y} v={vx},{vy}\n")
+        })
+        .collect()
+}
+
+/// A walled `size` by `size` warehouse and a long list of moves.
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut map = Grid::new(size, size, '#');
+
+    for p in map.positions().collect_vec() {
+        if (1..size - 1).contains(&p.row) && (1..size - 1).contains(&p.col) {
+            map[p] = match rng.below(10) {
+                0 => '#',
+                1 | 2 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    map[Pos::new(
+        rng.between(1, size as i64 - 2) as usize,
+        rng.between(1, size as i64 - 2) as usize,
+    )] = '@';
+
+    let moves = (0..size * size * 8)
+        .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+        .chunks(70)
+        .into_iter()
+        .map(|line| line.collect::<String>())
+        .join("\n");
+
+    format!("{map}\n{moves}\n")
+}
+
+/// A perfect maze on the odd cells of a `size` by `size` grid (rounded up to
+/// odd), with the start bottom left and the end top right.
+fn maze(rng: &mut Rng, size: usize) -> Grid<char> {
+    let size = size.max(5) | 1;
+    let mut maze = Grid::new(size, size, '#');
+    let start = Pos::new(size - 2, 1);
+    maze[start] = '.';
+    let mut stack = vec![start];
+
+    while let Some(&cell) = stack.last() {
+        let next = Dir::CARDINAL
+            .into_iter()
+            .filter_map(|d| {
+                let wall = maze.offset(cell, d)?;
+                let next = maze.offset(wall, d)?;
+                (next.row % 2 == 1 && next.col % 2 == 1 && maze[next] == '#')
+                    .then_some((wall, next))
+            })
+            .collect_vec();
+
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (wall, next) = *rng.pick(&next);
+        maze[wall] = '.';
+        maze[next] = '.';
+        stack.push(next);
+    }
+
+    maze
+}
+
+/// A maze with some walls knocked out, so there are loops and ties.
+pub fn day16(rng: &mut Rng, size: usize) -> String {
+    let mut maze = maze(rng, size);
+    let n = maze.nrow();
+
+    for p in maze.positions().collect_vec() {
+        let inner = (1..n - 1).contains(&p.row) && (1..n - 1).contains(&p.col);
+        if inner && (p.row + p.col) % 2 == 1 && rng.chance(0.05) {
+            maze[p] = '.';
+        }
+    }
+
+    maze[Pos::new(n - 2, 1)] = 'S';
+    maze[Pos::new(1, n - 2)] = 'E';
+    maze.to_string()
+}
+
+/// The puzzle's program, which prints the low bits of A and shifts A right by
+/// three each time round, with a register A that prints `size` digits.
+pub fn day17(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 21);
+    let mut a = rng.between(1, 7) as u64;
+    for _ in 1..digits {
+        a = a * 8 + rng.between(0, 7) as u64;
+    }
+
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,2,7,5,4,3,0,3,1,7,5,5,3,0\n"
+    )
+}
+
+/// `size` bytes falling into the 71 by 71 memory. The first kilobyte leaves
+/// a path to the exit; later ones may cut it.
+pub fn day18(rng: &mut Rng, size: usize) -> String {
+    let n = 71;
+    let end = Pos::new(n - 1, n - 1);
+
+    // a random staircase from corner to corner stays clear of the first kilobyte
+    let mut path = HashSet::from([Pos::default()]);
+    let mut p = Pos::default();
+    while p != end {
+        let down = p.col == n - 1 || (p.row < n - 1 && rng.chance(0.5));
+        p = if down { p + Dir::S } else { p + Dir::E };
+        path.insert(p);
+    }
+
+    let mut free = (0..n * n)
+        .map(|i| Pos::new(i / n, i % n))
+        .filter(|p| *p != Pos::default() && *p != end)
+        .collect_vec();
+    rng.shuffle(&mut free);
+    let (first, rest): (Vec<_>, Vec<_>) = free.into_iter().partition(|p| !path.contains(p));
+    let (first, more) = first.split_at(1024);
+    let mut rest = rest.into_iter().chain(more.iter().copied()).collect_vec();
+    rng.shuffle(&mut rest);
+
+    first
+        .iter()
+        .chain(&rest)
+        .take(size.max(1024))
+        .map(|p| format!("{},{}\n", p.col, p.row))
+        .collect()
+}
+
+/// Towels and `size` designs, about half made from those towels.
+pub fn day19(rng: &mut Rng, size: usize) -> String {
+    let colours = ['w', 'u', 'b', 'r', 'g'];
+    let stripes = |rng: &mut Rng, len| (0..len).map(|_| *rng.pick(&colours)).collect::<String>();
+
+    let towels = (0..size / 2 + 10)
+        .map(|_| {
+            let len = rng.between(1, 8);
+            stripes(rng, len)
+        })
+        // without a plain white towel some designs are impossible
+        .filter(|t| t != "w")
+        .unique()
+        .collect_vec();
+
+    let designs = (0..size.max(1)).map(|_| {
+        if rng.chance(0.5) {
+            let mut design = String::new();
+            while design.len() < 20 {
+                design.push_str(rng.pick(&towels).as_str());
+            }
+            design
+        } else {
+            let len = rng.between(20, 60);
+            stripes(rng, len)
+        }
+    });
+
+    format!(
+        "{}\n\n{}\n",
+        towels.join(", "),
+        designs.collect_vec().join("\n")
+    )
+}
+
+/// The single track through a perfect maze, as a racetrack.
+pub fn day20(rng: &mut Rng, size: usize) -> String {
+    let maze = maze(rng, size);
+    let n = maze.nrow();
+    let (start, end) = (Pos::new(n - 2, 1), Pos::new(1, n - 2));
+
+    let track = search::bfs(
+        start,
+        |&p| {
+            maze.neighbours4(p)
+                .filter(|&q| maze[q] == '.')
+                .collect_vec()
+        },
+        |&p| p == end,
+        false,
+    )
+    .path()
+    .unwrap();
+
+    let mut race = Grid::new(n, n, '#');
+    for p in track {
+        race[p] = '.';
+    }
+    race[start] = 'S';
+    race[end] = 'E';
+    race.to_string()
+}
+
+/// `size` door codes.
+pub fn day21(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{:03}A\n", rng.between(0, 999)))
+        .collect()
+}
+
+/// `size` initial secret numbers.
+pub fn day22(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{}\n", rng.between(1, 1 << 24)))
+        .collect()
+}
+
+/// A sparse network of `size` computers with one planted LAN party.
+pub fn day23(rng: &mut Rng, size: usize) -> String {
+    let mut names = ('a'..='z')
+        .cartesian_product('a'..='z')
+        .map(|(a, b)| format!("{a}{b}"))
+        .collect_vec();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(3, names.len()));
+
+    let n = names.len();
+    let mut edges = HashSet::new();
+    for i in 0..n {
+        for _ in 0..3 {
+            let j = rng.below(n);
+            if i != j {
+                edges.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    let party = n.min(13);
+    edges.extend((0..party).tuple_combinations::<(_, _)>());
+
+    let mut edges = edges.into_iter().sorted().collect_vec();
+    rng.shuffle(&mut edges);
+    edges
+        .into_iter()
+        .map(|(i, j)| format!("{}-{}\n", names[i], names[j]))
+        .collect()
+}
+
+/// A correct `size`-bit ripple-carry adder with random inputs, shuffled
+/// gates and random internal wire names.
+pub fn day24(rng: &mut Rng, size: usize) -> String {
+    let bits = size.clamp(2, 99);
+    let letters = ('a'..='w').collect_vec();
+    let mut used = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name = (0..3).map(|_| *rng.pick(&letters)).collect::<String>();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut gates = vec![];
+    let mut gate = |rng: &mut Rng, a: &str, op, b: &str, out: String| {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        gates.push(format!("{a} {op} {b} -> {out}"));
+        out
+    };
+
+    let carry = wire(rng);
+    let mut carry = gate(rng, "x00", "AND", "y00", carry);
+    gate(rng, "x00", "XOR", "y00", "z00".to_string());
+    for i in 1..bits {
+        let (x, y) = (format!("x{i:02}"), format!("y{i:02}"));
+        let half = wire(rng);
+        let half = gate(rng, &x, "XOR", &y, half);
+        let both = wire(rng);
+        let both = gate(rng, &x, "AND", &y, both);
+        gate(rng, &half, "XOR", &carry, format!("z{i:02}"));
+        let carried = wire(rng);
+        let carried = gate(rng, &half, "AND", &carry, carried);
+        let out = if i + 1 == bits {
+            format!("z{bits:02}")
+        } else {
+            wire(rng)
+        };
+        carry = gate(rng, &both, "OR", &carried, out);
+    }
+    rng.shuffle(&mut gates);
+
+    let mut input = String::new();
+    for xy in ['x', 'y'] {
+        for i in 0..bits {
+            writeln!(input, "{xy}{i:02}: {}", rng.below(2)).unwrap();
+        }
+    }
+    format!("{input}\n{}\n", gates.join("\n"))
+}
+
+/// `size` schematics, locks and keys mixed.
+pub fn day25(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let lock = rng.chance(0.5);
+            let heights = [(); 5].map(|_| rng.below(6));
+            let mut schematic = Grid::new(7, 5, '.');
+
+            for (col, &h) in heights.iter().enumerate() {
+                for row in 0..=h {
+                    let row = if lock { row } else { 6 - row };
+                    schematic[Pos::new(row, col)] = '#';
+                }
+            }
+            for col in 0..5 {
+                schematic[Pos::new(if lock { 0 } else { 6 }, col)] = '#';
+            }
+
+            schematic.to_string()
+        })
+        .join("\n")
+}
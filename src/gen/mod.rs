@@ -0,0 +1,180 @@
+mod days;
+
+/// Writes a random, valid puzzle input whose scale grows with `size`: lines,
+/// grid side or bits, depending on the day.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Generators by day, with the size that matches the real inputs.
+pub static REGISTRY: &[(usize, usize, Generator)] = &[
+    (1, 1000, days::day1),
+    (2, 1000, days::day2),
+    (3, 800, days::day3),
+    (4, 140, days::day4),
+    (5, 200, days::day5),
+    (6, 130, days::day6),
+    (7, 850, days::day7),
+    (8, 50, days::day8),
+    (9, 10000, days::day9),
+    (10, 45, days::day10),
+    (11, 8, days::day11),
+    (12, 140, days::day12),
+    (13, 320, days::day13),
+    (14, 500, days::day14),
+    (15, 50, days::day15),
+    (16, 141, days::day16),
+    (17, 16, days::day17),
+    (18, 3450, days::day18),
+    (19, 400, days::day19),
+    (20, 141, days::day20),
+    (21, 5, days::day21),
+    (22, 2000, days::day22),
+    (23, 520, days::day23),
+    (24, 45, days::day24),
+    (25, 500, days::day25),
+];
+
+/// The generator for `day` and its default size.
+pub fn get(day: usize) -> Option<(Generator, usize)> {
+    REGISTRY
+        .iter()
+        .find(|(d, _, _)| *d == day)
+        .map(|&(_, size, gen)| (gen, size))
+}
+
+/// A random input for `day`, the same for the same seed and size.
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Option<String> {
+    let (gen, default) = get(day)?;
+    Some(gen(&mut Rng::new(seed), size.unwrap_or(default)))
+}
+
+/// Checks fast solvers against their `reference` modules: parses the input
+/// generated for `day` with each seed at `size(seed)`, and asserts that every
+/// `(case, reference, fast)` answer `cases` gives for it agrees.
+#[cfg(all(test, feature = "reference"))]
+pub fn check_reference<T, E, A>(
+    day: usize,
+    seeds: std::ops::Range<u64>,
+    size: impl Fn(u64) -> usize,
+    parse: impl Fn(&str) -> Result<T, E>,
+    cases: impl Fn(u64, T) -> Vec<(String, A, A)>,
+) where
+    E: std::fmt::Debug,
+    A: PartialEq + std::fmt::Debug,
+{
+    for seed in seeds {
+        let input = parse(&generate(day, seed, Some(size(seed))).unwrap()).unwrap();
+        for (case, reference, fast) in cases(seed, input) {
+            assert_eq!(reference, fast, "day {day}, seed {seed}, {case}");
+        }
+    }
+}
+
+/// SplitMix64: small, fast and plenty random for test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo) as usize + 1) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{days, Part};
+    use itertools::Itertools;
+
+    #[test]
+    fn reproducible() {
+        for &(day, _, _) in REGISTRY {
+            assert_eq!(generate(day, 7, Some(10)), generate(day, 7, Some(10)));
+        }
+        assert_ne!(generate(1, 1, None), generate(1, 2, None));
+    }
+
+    #[test]
+    fn every_day_solves() {
+        for &(day, _, gen) in REGISTRY {
+            for seed in 0..3 {
+                let input = gen(&mut Rng::new(seed), 12);
+                let solution = days::get(day).unwrap()(&input)
+                    .unwrap_or_else(|e| panic!("day {day}, seed {seed}: {e}\n{input}"));
+
+                solution.part(Part::One);
+                solution.part(Part::Two);
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_sizes_solve() {
+        for &(day, _, gen) in REGISTRY {
+            for (seed, size) in (0..5).cartesian_product([0, 1]) {
+                let input = gen(&mut Rng::new(seed), size);
+                assert!(
+                    !input.trim().is_empty(),
+                    "day {day}, seed {seed}, size {size}"
+                );
+                let solution = days::get(day).unwrap()(&input).unwrap_or_else(|e| {
+                    panic!("day {day}, seed {seed}, size {size}: {e}\n{input}")
+                });
+
+                solution.part(Part::One);
+                solution.part(Part::Two);
+            }
+        }
+    }
+
+    #[test]
+    fn adder_is_correct() {
+        for seed in 0..5 {
+            let input = generate(24, seed, Some(20)).unwrap();
+            let bit = |name: &str| -> u64 {
+                input
+                    .lines()
+                    .filter_map(|l| l.strip_prefix(name))
+                    .filter_map(|l| l.split_once(": "))
+                    .map(|(i, v)| v.parse::<u64>().unwrap() << i.parse::<u64>().unwrap())
+                    .sum()
+            };
+
+            let solution = days::get(24).unwrap()(&input).unwrap();
+            assert_eq!((bit("x") + bit("y")).to_string(), solution.part(Part::One));
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::ParseError,
+    parse,
+    pos::{Dir, Pos},
+};
+
+/// A dense, row-major 2D grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    nrow: usize,
+    ncol: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(nrow: usize, ncol: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            nrow,
+            ncol,
+            cells: vec![fill; nrow * ncol],
+        }
+    }
+
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let nrow = rows.len();
+        let ncol = rows.first().map_or(0, |r| r.len());
+
+        assert!(
+            rows.iter().all(|r| r.len() == ncol),
+            "grid rows must all have {ncol} columns"
+        );
+
+        Self {
+            nrow,
+            ncol,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Builds a grid from the non-empty, trimmed lines of `input`, mapping
+    /// every character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(input, "", |c| Some(cell(c)))
+    }
+
+    /// Like [`Grid::parse`], but characters that `cell` rejects are reported
+    /// as not being `expected`.
+    pub fn try_parse(
+        input: &str,
+        expected: &'static str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        parse::finish(input, parse::grid(expected, cell))
+    }
+
+    pub fn nrow(&self) -> usize {
+        self.nrow
+    }
+
+    pub fn ncol(&self) -> usize {
+        self.ncol
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.nrow && pos.col < self.ncol
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.ncol + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.ncol + pos.col])
+    }
+
+    /// `pos` moved by `dir`, or `None` if that leaves the grid.
+    pub fn offset(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.checked_add(dir).filter(|&p| self.contains(p))
+    }
+
+    /// In-bounds orthogonal neighbours, clockwise from the top.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::CARDINAL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours, clockwise from the top.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::COMPASS
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let ncol = self.ncol;
+        (0..self.nrow).flat_map(move |row| (0..ncol).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find_map(|(p, v)| (v == value).then_some(p))
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter_map(move |(p, v)| (v == value).then_some(p))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.ncol..(row + 1) * self.ncol]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a zero size, and a grid without columns has no cells anyway
+        self.cells.chunks(self.ncol.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.ncol.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.ncol).map(|c| self.col(c))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            nrow: self.nrow,
+            ncol: self.ncol,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for {}x{} grid", self.nrow, self.ncol))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (nrow, ncol) = (self.nrow, self.ncol);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds for {nrow}x{ncol} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_and_render() {
+        let grid = Grid::parse("#.#\n..S\n", |c| c).unwrap();
+
+        assert_eq!((2, 3), (grid.nrow(), grid.ncol()));
+        assert_eq!('S', grid[Pos::new(1, 2)]);
+        assert_eq!(Some(Pos::new(1, 2)), grid.find(&'S'));
+        assert_eq!(2, grid.find_all(&'#').count());
+        assert_eq!(vec![&'#', &'.'], grid.col(0).collect::<Vec<_>>());
+        assert_eq!("#.#\n..S\n", grid.to_string());
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(2, grid.neighbours4(Pos::new(0, 0)).count());
+        assert_eq!(3, grid.neighbours8(Pos::new(0, 0)).count());
+        assert_eq!(4, grid.neighbours4(Pos::new(1, 1)).count());
+        assert_eq!(8, grid.neighbours8(Pos::new(1, 1)).count());
+        assert_eq!(None, grid.offset(Pos::new(2, 2), Dir::E));
+        assert_eq!(None, grid.get(Pos::new(3, 0)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("...\n....\n", |c| c).unwrap_err();
+        assert_eq!((2, 4, "`.`"), (err.line, err.column, err.found.as_str()));
+
+        let err = Grid::try_parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (2, 2, "a digit"),
+            (err.line, err.column, err.expected.as_str())
+        );
+    }
+}
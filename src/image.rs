@@ -0,0 +1,422 @@
+use std::{collections::HashMap, io, path::Path};
+
+use crate::{
+    days::{day12, day14, day16, day18},
+    error::ParseError,
+    grid::Grid,
+    pos::Pos,
+};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// An RGB picture, row-major from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// Every cell of `grid` as a `scale` by `scale` square.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut colour: impl FnMut(&T) -> Rgb) -> Self {
+        let mut image = Self::new(grid.ncol() * scale, grid.nrow() * scale, BLACK);
+        for (p, cell) in grid.iter() {
+            image.fill(p, scale, colour(cell));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Colours the square for grid cell `p` at the given scale.
+    pub fn fill(&mut self, p: Pos, scale: usize, colour: Rgb) {
+        for y in p.row * scale..(p.row + 1) * scale {
+            let row = y * self.width;
+            self.pixels[row + p.col * scale..row + (p.col + 1) * scale].fill(colour);
+        }
+    }
+
+    /// Binary PPM (`P6`).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// An 8-bit RGB PNG. The pixels are stored rather than compressed, which
+    /// keeps the encoder short at the cost of size.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(0xffff).peekable();
+        if blocks.peek().is_none() {
+            zlib.extend([1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            let len = u16::try_from(block.len()).expect("blocks are at most 0xffff bytes");
+            zlib.push(blocks.peek().is_none() as u8);
+            zlib.extend(len.to_le_bytes());
+            zlib.extend((!len).to_le_bytes());
+            zlib.extend(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib);
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes a PPM or PNG, going by the extension of `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: not a .ppm or .png path", path.display()),
+                ))
+            }
+        };
+        std::fs::write(path, bytes)
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// A looping animated GIF of `frames`, shown `delay` hundredths of a second
+/// each. The frames must share a size and at most 256 colours between them.
+pub fn gif(frames: &[Image], delay: u16) -> io::Result<Vec<u8>> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+    let first = frames.first().ok_or_else(|| invalid("no frames".into()))?;
+    let (width, height) = (first.width, first.height);
+    if let Some(i) = frames
+        .iter()
+        .position(|f| (f.width, f.height) != (width, height))
+    {
+        return Err(invalid(format!("frame {i} is not {width}x{height}")));
+    }
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(invalid(format!(
+            "{width}x{height} pixels, GIF allows 65535 a side"
+        )));
+    };
+
+    let mut palette: Vec<Rgb> = vec![];
+    let mut index = HashMap::new();
+    for &px in frames.iter().flat_map(|f| f.pixels.iter()) {
+        index.entry(px).or_insert_with(|| {
+            palette.push(px);
+            palette.len() - 1
+        });
+    }
+    if palette.len() > 256 {
+        return Err(invalid(format!(
+            "{} colours, GIF allows 256",
+            palette.len()
+        )));
+    }
+
+    // the colour table has 2^bits entries
+    let bits = (1..=8).find(|b| palette.len() <= 1 << b).unwrap();
+    palette.resize(1 << bits, BLACK);
+
+    let mut out = b"GIF89a".to_vec();
+    out.extend(width.to_le_bytes());
+    out.extend(height.to_le_bytes());
+    out.extend([0xf0 | (bits - 1), 0, 0]);
+    out.extend(palette.iter().flatten());
+    // loop forever
+    out.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let min_code = bits.max(2);
+    for frame in frames {
+        out.extend([0x21, 0xf9, 4, 0]);
+        out.extend(delay.to_le_bytes());
+        out.extend([0, 0]);
+
+        out.push(0x2c);
+        out.extend([0, 0, 0, 0]);
+        out.extend(width.to_le_bytes());
+        out.extend(height.to_le_bytes());
+        out.push(0);
+
+        out.push(min_code);
+        // at most 256 colours, checked above
+        let data = lzw(frame.pixels.iter().map(|px| index[px] as u16), min_code);
+        for block in data.chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0);
+    }
+
+    out.push(0x3b);
+    Ok(out)
+}
+
+pub fn save_gif(frames: &[Image], delay: u16, path: &Path) -> io::Result<()> {
+    std::fs::write(path, gif(frames, delay)?)
+}
+
+pub type Painter = fn(&str) -> Result<Image, ParseError>;
+
+/// Days with a picture of their answer, by day.
+pub static REGISTRY: &[(usize, Painter)] = &[
+    (12, day12::picture),
+    (14, day14::picture),
+    (16, day16::picture),
+    (18, day18::picture),
+];
+
+pub fn get(day: usize) -> Option<Painter> {
+    REGISTRY
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, painter)| painter)
+}
+
+/// Variable-width LZW as GIF wants it: codes packed from the low bit up,
+/// widening as the table grows and starting over once it is full.
+fn lzw(indices: impl Iterator<Item = u16>, min_code: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code;
+    let end = clear + 1;
+
+    let mut out = vec![];
+    let (mut acc, mut nacc) = (0u32, 0);
+    let mut emit = |code: u16, width: u8| {
+        acc |= (code as u32) << nacc;
+        nacc += width;
+        while nacc >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            nacc -= 8;
+        }
+    };
+
+    let mut table: HashMap<(u16, u16), u16> = HashMap::new();
+    let mut width = min_code + 1;
+    let mut next = end + 1;
+    let mut run: Option<u16> = None;
+    emit(clear, width);
+
+    for i in indices {
+        let Some(prefix) = run else {
+            run = Some(i);
+            continue;
+        };
+        if let Some(&code) = table.get(&(prefix, i)) {
+            run = Some(code);
+            continue;
+        }
+
+        emit(prefix, width);
+        if next < 4096 {
+            table.insert((prefix, i), next);
+            next += 1;
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            emit(clear, width);
+            table.clear();
+            width = min_code + 1;
+            next = end + 1;
+        }
+        run = Some(i);
+    }
+
+    if let Some(prefix) = run {
+        emit(prefix, width);
+    }
+    emit(end, width);
+    if nacc > 0 {
+        out.push(acc as u8);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Undoes [`lzw`] the way a GIF reader would.
+    fn unlzw(data: &[u8], min_code: u8) -> Vec<u16> {
+        let clear = 1u16 << min_code;
+        let mut table: Vec<Vec<u16>> = vec![];
+        let mut width = min_code + 1;
+        let mut prev: Option<Vec<u16>> = None;
+        let mut out = vec![];
+        let (mut acc, mut nacc, mut bytes) = (0u32, 0, data.iter());
+
+        loop {
+            while nacc < width {
+                acc |= (*bytes.next().unwrap() as u32) << nacc;
+                nacc += 8;
+            }
+            let code = (acc & ((1 << width) - 1)) as u16;
+            acc >>= width;
+            nacc -= width;
+
+            if code == clear {
+                table = (0..clear + 2).map(|c| vec![c]).collect();
+                width = min_code + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (table.get(code as usize), &prev) {
+                (Some(e), _) => e.clone(),
+                (None, Some(p)) => [p.clone(), vec![p[0]]].concat(),
+                (None, None) => panic!("code {code} before any output"),
+            };
+            if let Some(p) = prev {
+                table.push([p, vec![entry[0]]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            out.extend(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn ppm_and_png() {
+        let mut image = Image::new(2, 1, WHITE);
+        image.fill(Pos::new(0, 1), 1, [1, 2, 3]);
+
+        assert_eq!(
+            b"P6\n2 1\n255\n\xff\xff\xff\x01\x02\x03".to_vec(),
+            image.to_ppm()
+        );
+
+        let png = image.to_png();
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        // a well-known value: the CRC of an empty IEND chunk
+        assert_eq!(0xae426082, crc32(b"IEND"));
+        assert_eq!(&[0xae, 0x42, 0x60, 0x82], &png[png.len() - 4..]);
+        let idat = png.windows(4).position(|w| w == b"IDAT").unwrap();
+        // zlib header, one final stored block of 7 bytes, then filter 0 and the pixels
+        assert_eq!(
+            b"\x78\x01\x01\x07\x00\xf8\xff\x00\xff\xff\xff\x01\x02\x03",
+            &png[idat + 4..idat + 18]
+        );
+    }
+
+    #[test]
+    fn lzw_round_trips() {
+        let mut rng = crate::gen::Rng::new(3);
+        for (min_code, len) in [(2, 10), (2, 5000), (4, 20000), (8, 100000)] {
+            let colours = 1 << min_code;
+            let indices = (0..len)
+                .map(|i| {
+                    if i % 7 < 4 {
+                        0
+                    } else {
+                        rng.below(colours) as u16
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let data = lzw(indices.iter().copied(), min_code);
+            assert_eq!(
+                indices,
+                unlzw(&data, min_code),
+                "{min_code} bits, {len} pixels"
+            );
+        }
+    }
+
+    #[test]
+    fn gif_frames() {
+        let frames = [Image::new(3, 2, BLACK), Image::new(3, 2, WHITE)];
+        let gif = gif(&frames, 10).unwrap();
+
+        assert_eq!(b"GIF89a\x03\x00\x02\x00\xf0", &gif[..11]);
+        assert_eq!(&[0, 0, 0, 255, 255, 255], &gif[13..19]);
+        assert_eq!(Some(&0x3b), gif.last());
+        assert!(super::gif(&[Image::new(1, 1, BLACK), Image::new(2, 1, BLACK)], 10).is_err());
+
+        let wide = super::gif(&[Image::new(1 << 16, 1, BLACK)], 10).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, wide.kind());
+        assert!(super::gif(&[Image::new(1, 0xffff, BLACK)], 10).is_ok());
+    }
+
+    #[test]
+    fn every_picture_paints() {
+        for &(day, painter) in REGISTRY {
+            let input = crate::gen::generate(day, 0, None).unwrap();
+            let image = painter(&input).unwrap();
+            assert!(image.width() > 0 && image.height() > 0, "day {day}");
+            assert!(
+                image.pixels.iter().any(|&px| px != image.pixels[0]),
+                "day {day} is blank"
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    fmt::Display,
+    io::{self, Read as _},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    NotUtf8(PathBuf),
+    Empty(PathBuf),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(p) => write!(f, "{}: input file not found", p.display()),
+            InputError::Unreadable(p, e) => write!(f, "{}: {}", p.display(), e),
+            InputError::NotUtf8(p) => write!(f, "{}: input is not valid UTF-8", p.display()),
+            InputError::Empty(p) => write!(f, "{}: input is empty", p.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// `$AOC_INPUT_DIR` if set, otherwise the `input` directory of this crate, so
+/// that lookups do not depend on the working directory.
+pub fn input_dir() -> PathBuf {
+    match std::env::var_os("AOC_INPUT_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
+
+pub fn input_path(day: usize) -> PathBuf {
+    input_dir().join(format!("day{day}.txt"))
+}
+
+/// The name of a day's own input, `dayN.txt`.
+pub const DEFAULT_FIXTURE: &str = "default";
+
+/// One input of a day, with the file its expected answers are kept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: PathBuf,
+}
+
+impl Fixture {
+    pub fn read(&self) -> Result<String, InputError> {
+        read_input_file(&self.input)
+    }
+}
+
+/// The day's own input, answered in `dayN.toml` in
+/// [`answers_dir`](crate::answers::answers_dir), then every `dayN/<name>.txt`
+/// by name, answered in `dayN/<name>.toml` beside it. A `dayN/default.txt` is
+/// ignored, as the name is taken.
+pub fn fixtures(day: usize) -> Vec<Fixture> {
+    fixtures_in(&input_dir(), &crate::answers::answers_dir(), day)
+}
+
+pub fn fixture(day: usize, name: &str) -> Option<Fixture> {
+    fixtures(day).into_iter().find(|f| f.name == name)
+}
+
+fn fixtures_in(dir: &Path, answers: &Path, day: usize) -> Vec<Fixture> {
+    let mut named = std::fs::read_dir(dir.join(format!("day{day}")))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let input = entry.ok()?.path();
+            let name = input.file_stem()?.to_str()?.to_string();
+            (input.extension()? == "txt" && name != DEFAULT_FIXTURE).then(|| Fixture {
+                answers: input.with_extension("toml"),
+                input,
+                name,
+            })
+        })
+        .collect::<Vec<_>>();
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let default = Fixture {
+        name: DEFAULT_FIXTURE.to_string(),
+        input: dir.join(format!("day{day}.txt")),
+        answers: answers.join(format!("day{day}.toml")),
+    };
+    std::iter::once(default).chain(named).collect()
+}
+
+pub fn read_input(day: usize) -> Result<String, InputError> {
+    read_input_file(&input_path(day))
+}
+
+pub fn read_input_file(path: &Path) -> Result<String, InputError> {
+    let bytes = std::fs::read(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Unreadable(path.to_path_buf(), e),
+    })?;
+
+    check(path, bytes)
+}
+
+pub fn read_input_stdin() -> Result<String, InputError> {
+    let path = Path::new("<stdin>");
+    let mut bytes = vec![];
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|e| InputError::Unreadable(path.to_path_buf(), e))?;
+
+    check(path, bytes)
+}
+
+fn check(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    let input = String::from_utf8(bytes).map_err(|_| InputError::NotUtf8(path.to_path_buf()))?;
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn errors() {
+        let dir = std::env::temp_dir().join("aoc_2024_input_test");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.txt");
+        assert!(matches!(
+            read_input_file(&missing),
+            Err(InputError::Missing(_))
+        ));
+
+        let empty = dir.join("empty.txt");
+        std::fs::write(&empty, "\n  \n").unwrap();
+        assert!(matches!(read_input_file(&empty), Err(InputError::Empty(_))));
+
+        let binary = dir.join("binary.txt");
+        std::fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(matches!(
+            read_input_file(&binary),
+            Err(InputError::NotUtf8(_))
+        ));
+
+        assert!(matches!(
+            read_input_file(&dir),
+            Err(InputError::Unreadable(..))
+        ));
+    }
+
+    #[test]
+    fn finds_fixtures() {
+        let dir = std::env::temp_dir().join("aoc_2024_fixture_test");
+        std::fs::create_dir_all(dir.join("day3")).unwrap();
+        for file in [
+            "bob.txt",
+            "alice.txt",
+            "alice.toml",
+            "default.txt",
+            "notes.md",
+        ] {
+            std::fs::write(dir.join("day3").join(file), "x").unwrap();
+        }
+
+        let fixtures = fixtures_in(&dir, &dir, 3);
+        let names = fixtures.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec![DEFAULT_FIXTURE, "alice", "bob"], names);
+        assert_eq!(dir.join("day3.txt"), fixtures[0].input);
+        assert_eq!(dir.join("day3.toml"), fixtures[0].answers);
+        assert_eq!(dir.join("day3/alice.toml"), fixtures[1].answers);
+
+        assert_eq!(1, fixtures_in(&dir, &dir, 4).len());
+    }
+
+    #[test]
+    fn resolves_from_manifest_dir() {
+        if std::env::var_os("AOC_INPUT_DIR").is_none() {
+            assert!(input_path(1).is_absolute());
+            assert!(read_input(1).is_ok());
+        }
+    }
+}
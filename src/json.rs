@@ -0,0 +1,246 @@
+use std::fmt::Display;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{escaped_transform, is_not, tag, take},
+    character::complete::{char, multispace0},
+    combinator::{all_consuming, map, map_res, value},
+    multi::separated_list0,
+    number::complete::double,
+    sequence::{delimited, preceded, separated_pair},
+    IResult,
+};
+
+/// A minimal JSON value, enough for the runner's machine-readable output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        all_consuming(delimited(multispace0, parse_value, multispace0))(input)
+            .map(|(_, json)| json)
+            .map_err(|e| format!("invalid JSON: {e}"))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Number(value)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Json::Number(value as f64)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+fn write_str(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Compact, single-line rendering.
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Number(n) if !n.is_finite() => write!(f, "null"),
+            Json::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Json::Number(n) => write!(f, "{n}"),
+            Json::String(s) => write_str(f, s),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_str(f, k)?;
+                    write!(f, ":{v}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn parse_string(input: &str) -> IResult<&str, String> {
+    let escape = alt((
+        value('"', char('"')),
+        value('\\', char('\\')),
+        value('/', char('/')),
+        value('\n', char('n')),
+        value('\r', char('r')),
+        value('\t', char('t')),
+        map_res(preceded(char('u'), take(4usize)), |hex: &str| {
+            u32::from_str_radix(hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or("invalid escape")
+        }),
+    ));
+
+    alt((
+        value(String::new(), tag("\"\"")),
+        delimited(
+            char('"'),
+            escaped_transform(is_not("\"\\"), '\\', escape),
+            char('"'),
+        ),
+    ))(input)
+}
+
+fn ws<'a, O>(
+    f: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    delimited(multispace0, f, multispace0)
+}
+
+fn parse_value(input: &str) -> IResult<&str, Json> {
+    alt((
+        value(Json::Null, tag("null")),
+        value(Json::Bool(true), tag("true")),
+        value(Json::Bool(false), tag("false")),
+        map(parse_string, Json::String),
+        map(double, Json::Number),
+        map(
+            delimited(
+                char('['),
+                separated_list0(char(','), ws(parse_value)),
+                delimited(multispace0, char(']'), multispace0),
+            ),
+            Json::Array,
+        ),
+        map(
+            delimited(
+                char('{'),
+                separated_list0(
+                    char(','),
+                    separated_pair(ws(parse_string), char(':'), ws(parse_value)),
+                ),
+                delimited(multispace0, char('}'), multispace0),
+            ),
+            Json::Object,
+        ),
+    ))(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let json = Json::Object(vec![
+            ("day".to_string(), 7.into()),
+            ("answer".to_string(), "a\"b\n".into()),
+            ("ratio".to_string(), 0.5.into()),
+            (
+                "parts".to_string(),
+                Json::Array(vec![true.into(), Json::Null, Json::Array(vec![])]),
+            ),
+            ("missing".to_string(), None::<usize>.into()),
+        ]);
+
+        let text = json.to_string();
+        assert_eq!(
+            r#"{"day":7,"answer":"a\"b\n","ratio":0.5,"parts":[true,null,[]],"missing":null}"#,
+            text
+        );
+        assert_eq!(Ok(json), Json::parse(&text));
+    }
+
+    #[test]
+    fn parse() {
+        let json = Json::parse(" { \"a\" : [ 1 , 2e3 ] , \"b\" : \"\\u0041\" } ").unwrap();
+
+        assert_eq!(
+            Some(2000.0),
+            json.get("a").unwrap().as_array().unwrap()[1].as_f64()
+        );
+        assert_eq!(Some("A"), json.get("b").unwrap().as_str());
+        assert!(Json::parse("{\"a\":}").is_err());
+        assert!(Json::parse("[1] 2").is_err());
+    }
+}
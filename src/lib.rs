@@ -1,13 +1,27 @@
-use std::io::Read as _;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod gen;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod memo;
+pub mod parse;
+pub mod pool;
+pub mod pos;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod viz;
 
-fn get_file_v1(day: usize) -> std::fs::File {
-    let path = format!("input/day{day}.txt");
-    std::fs::File::open(path).unwrap()
-}
+pub use error::ParseError;
+pub use input::{read_input, InputError};
+pub use solution::{DynSolution, Solution};
 
-pub fn read_input_v1(day: usize) -> String {
-    let mut f = get_file_v1(day);
-    let mut buf = String::new();
-    f.read_to_string(&mut buf).unwrap();
-    buf
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
 }
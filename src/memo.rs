@@ -0,0 +1,150 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash, marker::PhantomData};
+
+/// Where a [`Memo`] keeps its answers.
+pub trait Table<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Hash + Eq, V> Table<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// A table for keys that map to small indices, kept in a growing `Vec`.
+pub struct Dense<K, V> {
+    index: fn(&K) -> usize,
+    cells: Vec<Option<V>>,
+}
+
+impl<K, V> Dense<K, V> {
+    pub fn new(index: fn(&K) -> usize) -> Self {
+        Self {
+            index,
+            cells: vec![],
+        }
+    }
+}
+
+impl<K, V> Table<K, V> for Dense<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.cells.get((self.index)(key))?.as_ref()
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let i = (self.index)(&key);
+        if i >= self.cells.len() {
+            self.cells.resize_with(i + 1, || None);
+        }
+        self.cells[i] = Some(value);
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+/// Answers of a recursive function by argument, computed once each.
+pub struct Memo<K, V, T = HashMap<K, V>> {
+    table: T,
+    stats: Stats,
+    entries: PhantomData<(K, V)>,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_table(HashMap::new())
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V: Clone, T: Table<K, V>> Memo<K, V, T> {
+    pub fn with_table(table: T) -> Self {
+        Self {
+            table,
+            stats: Stats::default(),
+            entries: PhantomData,
+        }
+    }
+
+    /// The answer for `key`, from `f` the first time. `f` is handed the memo
+    /// to recurse through, and its answer is stored under `key` itself.
+    pub fn get(&mut self, key: K, f: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.table.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = f(self, &key);
+        self.table.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fib<T: Table<u64, u64>>(memo: &mut Memo<u64, u64, T>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get(n, |memo, &n| fib(memo, n - 1) + fib(memo, n - 2))
+    }
+
+    #[test]
+    fn remembers() {
+        let mut memo = Memo::new();
+        assert_eq!(12586269025, fib(&mut memo, 50));
+        assert_eq!(
+            Stats {
+                hits: 47,
+                misses: 49
+            },
+            memo.stats()
+        );
+
+        assert_eq!(55, fib(&mut memo, 10));
+        assert_eq!(48, memo.stats().hits);
+    }
+
+    #[test]
+    fn dense() {
+        let mut memo = Memo::with_table(Dense::new(|&n| n as usize));
+        assert_eq!(12586269025, fib(&mut memo, 50));
+        assert_eq!(
+            Stats {
+                hits: 47,
+                misses: 49
+            },
+            memo.stats()
+        );
+        assert_eq!(
+            None,
+            Table::get(&Dense::<u64, u64>::new(|&n| n as usize), &3)
+        );
+    }
+}
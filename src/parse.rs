@@ -0,0 +1,297 @@
+use std::{borrow::Cow, str::FromStr};
+
+use nom::{
+    branch::alt,
+    bytes::complete::{is_not, tag},
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{all_consuming, cut, eof, map_res, not, opt, recognize},
+    error::{context, ContextError, ErrorKind, FromExternalError},
+    multi::{many1, many1_count, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{error::ParseError, grid::Grid};
+
+/// A nom error that keeps what was expected at the furthest point reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// Where parsing went wrong, a slice of the original input.
+    pub input: &'a str,
+    pub expected: Cow<'static, str>,
+}
+
+pub type PResult<'a, O> = IResult<&'a str, O, Error<'a>>;
+
+impl<'a> Error<'a> {
+    pub fn new(input: &'a str, expected: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            input,
+            expected: expected.into(),
+        }
+    }
+
+    /// An unrecoverable error, which stops alternatives and lists from
+    /// backtracking past it.
+    pub fn fail<O>(input: &'a str, expected: impl Into<Cow<'static, str>>) -> PResult<'a, O> {
+        Err(nom::Err::Failure(Self::new(input, expected)))
+    }
+
+    /// Turns the error into a [`ParseError`] located in `all`.
+    pub fn locate(self, all: &str) -> ParseError {
+        ParseError::at(all, self.input, self.expected)
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Eof => Self::new(input, "end of input"),
+            ErrorKind::Digit => Self::new(input, "a digit"),
+            ErrorKind::CrLf => Self::new(input, "end of line"),
+            ErrorKind::Space | ErrorKind::MultiSpace => Self::new(input, "whitespace"),
+            ErrorKind::Alpha => Self::new(input, "a letter"),
+            kind => Self::new(input, kind.description().to_lowercase()),
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self::new(input, quote(c.encode_utf8(&mut [0; 4])))
+    }
+
+    /// Prefers whichever alternative got further, or names both.
+    fn or(self, other: Self) -> Self {
+        match self.input.as_ptr().cmp(&other.input.as_ptr()) {
+            std::cmp::Ordering::Greater => self,
+            std::cmp::Ordering::Less => other,
+            _ if self.expected == other.expected => self,
+            _ => Self::new(
+                self.input,
+                format!("{} or {}", self.expected, other.expected),
+            ),
+        }
+    }
+}
+
+/// A context names what was expected, unless the inner parser got further.
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        if other.input.as_ptr() == input.as_ptr() {
+            Self::new(input, ctx)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// `s` in backticks, with control characters escaped.
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("`");
+    for c in s.chars() {
+        if c.is_control() {
+            quoted.extend(c.escape_debug());
+        } else {
+            quoted.push(c);
+        }
+    }
+    quoted.push('`');
+    quoted
+}
+
+/// Runs `parser` over the whole of `input`, ignoring surrounding whitespace.
+pub fn finish<'a, O>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> Result<O, ParseError> {
+    match all_consuming(delimited(multispace0, parser, multispace0))(input) {
+        Ok((_, out)) => Ok(out),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e.locate(input)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::after(input, input, "more input")),
+    }
+}
+
+/// Matches `text` exactly.
+pub fn lit<'a>(text: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |input| tag(text)(input).map_err(|e| e.map(|e: Error| Error::new(e.input, quote(text))))
+}
+
+/// An unsigned integer of any width.
+pub fn unsigned<'a, T: FromStr>(input: &'a str) -> PResult<'a, T> {
+    context("a number", map_res(digit1, str::parse))(input)
+}
+
+/// An integer of any width with an optional sign.
+pub fn signed<'a, T: FromStr>(input: &'a str) -> PResult<'a, T> {
+    context(
+        "a number",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// Items separated by commas, with optional spaces around them. Every comma
+/// must be followed by an item.
+pub fn comma_list<'a, O>(
+    mut item: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+
+        while let Ok((rest, _)) = delimited(space0, char::<_, Error>(','), space0)(input) {
+            let (rest, next) = cut(&mut item)(rest)?;
+            items.push(next);
+            input = rest;
+        }
+
+        Ok((input, items))
+    }
+}
+
+/// Items on one line separated by spaces or tabs.
+pub fn spaced<'a, O>(
+    item: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    separated_list1(space1, item)
+}
+
+/// `key: value`, as in `Register A: 729`.
+pub fn key_value<'a, O>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    preceded(tuple((lit(key), char(':'), space0)), value)
+}
+
+/// Trailing spaces and a line break, or the end of input.
+pub fn end_of_line(input: &str) -> PResult<'_, ()> {
+    let (input, _) = space0(input)?;
+    let (input, _) = context("end of line", alt((line_ending, eof)))(input)?;
+    Ok((input, ()))
+}
+
+/// `item` followed by the end of its line.
+pub fn line<'a, O>(
+    item: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    terminated(item, end_of_line)
+}
+
+/// One `item` per line, up to a blank line or the end of input. Lines may be
+/// indented. A line that is not blank must parse, so errors point into it.
+pub fn lines<'a, O>(
+    item: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    many1(preceded(
+        context("a non-blank line", not(end_of_line)),
+        cut(line(preceded(space0, item))),
+    ))
+}
+
+/// One or more blank lines, left between sections by [`lines`].
+pub fn blank_lines(input: &str) -> PResult<'_, ()> {
+    let (input, _) = context("a blank line", many1_count(pair(space0, line_ending)))(input)?;
+    Ok((input, ()))
+}
+
+/// Groups of lines separated by blank lines.
+pub fn blocks<'a, O>(
+    block: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    separated_list1(blank_lines, block)
+}
+
+/// A block of lines of characters, one cell each. Characters `cell` rejects
+/// are reported as not being `expected`, and all rows must be equally long.
+pub fn grid<'a, T>(
+    expected: &'static str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> PResult<'a, Grid<T>> {
+    move |input| {
+        let (input, lines) = lines(is_not("\r\n"))(input)?;
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(lines.len());
+
+        for line in lines.into_iter().map(str::trim) {
+            let mut row = vec![];
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(t) => row.push(t),
+                    None => return Error::fail(&line[i..i + c.len_utf8()], expected),
+                }
+            }
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let at = line
+                        .char_indices()
+                        .nth(first.len())
+                        .map_or(line.len(), |(i, _)| i);
+                    return Error::fail(&line[at..], format!("a row of {} cells", first.len()));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok((input, Grid::from_rows(rows)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_and_lists() {
+        assert_eq!(Ok(("", 200u8)), unsigned("200"));
+        assert!(unsigned::<u8>("300").is_err());
+        assert_eq!(Ok((",", -42i64)), signed("-42,"));
+        assert_eq!(
+            Ok(vec![vec![1, -2], vec![3]]),
+            finish("1, -2\n3\n", lines(comma_list(signed::<i32>)))
+        );
+        assert_eq!(
+            Ok(vec![vec![vec![1u8, 2], vec![3]], vec![vec![4]]]),
+            finish("1 2\n3 \n\n\n4\n", blocks(lines(spaced(unsigned))))
+        );
+        assert_eq!(
+            Ok(729u32),
+            finish("Register A: 729", key_value("Register A", unsigned))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = finish("1 2\n3 x4\n", lines(spaced(unsigned::<u32>))).unwrap_err();
+        assert_eq!(
+            (2, 3, "end of line", "`x4`"),
+            (
+                err.line,
+                err.column,
+                err.expected.as_str(),
+                err.found.as_str()
+            )
+        );
+
+        let err = finish("1\nx\n", lines(unsigned::<u32>)).unwrap_err();
+        assert_eq!(
+            (2, 1, "a number"),
+            (err.line, err.column, err.expected.as_str())
+        );
+
+        let err = finish("do(", alt((lit("do()"), lit("don't()")))).unwrap_err();
+        assert_eq!("`do()` or `don't()`", err.expected);
+
+        let err = finish("#.\n.x\n", grid("a tile", |c| (c != 'x').then_some(c))).unwrap_err();
+        assert_eq!((2, 2, "`x`"), (err.line, err.column, err.found.as_str()));
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Scoped worker threads that pull items off a shared slice one at a time, so
+/// uneven tasks still balance across workers.
+#[derive(Debug, Clone, Copy)]
+pub struct Pool {
+    threads: usize,
+}
+
+impl Default for Pool {
+    /// One worker per available core.
+    fn default() -> Self {
+        Self::new(thread::available_parallelism().map_or(1, NonZeroUsize::get))
+    }
+}
+
+impl Pool {
+    /// At least one worker, whatever `threads` says.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Folds every item into a per-worker accumulator started from `init`,
+    /// then merges the accumulators. The merge order is unspecified.
+    pub fn reduce<T, A>(
+        &self,
+        items: &[T],
+        init: impl Fn() -> A + Sync,
+        fold: impl Fn(A, &T) -> A + Sync,
+        merge: impl Fn(A, A) -> A,
+    ) -> A
+    where
+        T: Sync,
+        A: Send,
+    {
+        self.reduce_indices(items.len(), init, |acc, i| fold(acc, &items[i]), merge)
+    }
+
+    /// `items.iter().map(f).collect()`, in parallel and in order.
+    pub fn map<T, R>(&self, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+    {
+        let mut indexed = self.reduce_indices(
+            items.len(),
+            Vec::new,
+            |mut acc, i| {
+                acc.push((i, f(&items[i])));
+                acc
+            },
+            |mut a, b| {
+                a.extend(b);
+                a
+            },
+        );
+
+        indexed.sort_unstable_by_key(|&(i, _)| i);
+        indexed.into_iter().map(|(_, r)| r).collect()
+    }
+
+    fn reduce_indices<A: Send>(
+        &self,
+        len: usize,
+        init: impl Fn() -> A + Sync,
+        fold: impl Fn(A, usize) -> A + Sync,
+        merge: impl Fn(A, A) -> A,
+    ) -> A {
+        let next = AtomicUsize::new(0);
+        let workers = self.threads.min(len);
+
+        let work = || {
+            let mut acc = init();
+            loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= len {
+                    return acc;
+                }
+                acc = fold(acc, i);
+            }
+        };
+
+        if workers <= 1 {
+            return work();
+        }
+
+        thread::scope(|s| {
+            let handles = (0..workers).map(|_| s.spawn(work)).collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .fold(init(), &merge)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_keeps_order() {
+        let items = (0..1000).collect::<Vec<u64>>();
+
+        for threads in [1, 3, 16] {
+            let squares = Pool::new(threads).map(&items, |x| x * x);
+            assert_eq!(items.iter().map(|x| x * x).collect::<Vec<_>>(), squares);
+        }
+    }
+
+    #[test]
+    fn reduce() {
+        let items = (1..=100).collect::<Vec<u64>>();
+
+        assert_eq!(
+            5050,
+            Pool::new(4).reduce(&items, || 0, |a, x| a + x, |a, b| a + b)
+        );
+        assert_eq!(
+            0,
+            Pool::new(4).reduce(&[], || 0, |a, x: &u64| a + x, |a, b| a + b)
+        );
+        assert_eq!(
+            0,
+            Pool::new(0).reduce(&[0], || 0, |a, x| a + x, |a, b| a + b)
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub},
+};
+
+/// A grid position, `row` counting down and `col` counting right.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A signed step between positions; also the signed form of a [`Pos`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Dir {
+    pub dr: isize,
+    pub dc: isize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// `None` if the step would leave the non-negative quadrant.
+    pub fn checked_add(self, dir: Dir) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(dir.dr)?,
+            self.col.checked_add_signed(dir.dc)?,
+        ))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Dir {
+    pub const N: Dir = Dir::new(-1, 0);
+    pub const NE: Dir = Dir::new(-1, 1);
+    pub const E: Dir = Dir::new(0, 1);
+    pub const SE: Dir = Dir::new(1, 1);
+    pub const S: Dir = Dir::new(1, 0);
+    pub const SW: Dir = Dir::new(1, -1);
+    pub const W: Dir = Dir::new(0, -1);
+    pub const NW: Dir = Dir::new(-1, -1);
+
+    /// Clockwise from north.
+    pub const CARDINAL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+    /// Clockwise from north.
+    pub const COMPASS: [Dir; 8] = [
+        Dir::N,
+        Dir::NE,
+        Dir::E,
+        Dir::SE,
+        Dir::S,
+        Dir::SW,
+        Dir::W,
+        Dir::NW,
+    ];
+
+    pub const fn new(dr: isize, dc: isize) -> Self {
+        Self { dr, dc }
+    }
+
+    /// `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::N),
+            '>' => Some(Dir::E),
+            'v' => Some(Dir::S),
+            '<' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    /// The arrow [`Dir::from_arrow`] reads, for the four cardinal directions.
+    pub fn arrow(self) -> Option<char> {
+        match self {
+            Dir::N => Some('^'),
+            Dir::E => Some('>'),
+            Dir::S => Some('v'),
+            Dir::W => Some('<'),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir::new(self.dc, -self.dr)
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir::new(-self.dc, self.dr)
+    }
+
+    pub fn reverse(self) -> Self {
+        -self
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.dr.unsigned_abs() + self.dc.unsigned_abs()
+    }
+}
+
+impl TryFrom<Dir> for Pos {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Dir) -> Result<Self, Self::Error> {
+        Ok(Pos::new(value.dr.try_into()?, value.dc.try_into()?))
+    }
+}
+
+impl TryFrom<Pos> for Dir {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Pos) -> Result<Self, Self::Error> {
+        Ok(Dir::new(value.row.try_into()?, value.col.try_into()?))
+    }
+}
+
+/// Panics if the step leaves the non-negative quadrant; see [`Pos::checked_add`].
+impl Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Dir) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("{self:?} + {rhs:?} is not a valid position"))
+    }
+}
+
+impl AddAssign<Dir> for Pos {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Dir> for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Dir) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub for Pos {
+    type Output = Dir;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dir::new(
+            self.row as isize - rhs.row as isize,
+            self.col as isize - rhs.col as isize,
+        )
+    }
+}
+
+impl Add for Dir {
+    type Output = Dir;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dir::new(self.dr + rhs.dr, self.dc + rhs.dc)
+    }
+}
+
+impl Sub for Dir {
+    type Output = Dir;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Dir {
+    type Output = Dir;
+
+    fn neg(self) -> Self::Output {
+        Dir::new(-self.dr, -self.dc)
+    }
+}
+
+impl Mul<isize> for Dir {
+    type Output = Dir;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Dir::new(self.dr * rhs, self.dc * rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::E, Dir::N.turn_right());
+        assert_eq!(Dir::W, Dir::N.turn_left());
+        assert_eq!(Dir::S, Dir::N.reverse());
+        assert_eq!(Dir::NE, Dir::NW.turn_right());
+        assert_eq!(Some(Dir::W), Dir::W.arrow().and_then(Dir::from_arrow));
+        assert_eq!(None, Dir::NE.arrow());
+
+        for d in Dir::COMPASS {
+            assert_eq!(d, d.turn_right().turn_right().turn_right().turn_right());
+            assert_eq!(d, d.turn_left().turn_right());
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = Pos::new(2, 3);
+
+        assert_eq!(Pos::new(1, 3), p + Dir::N);
+        assert_eq!(Pos::new(4, 7), p + Dir::new(1, 2) * 2);
+        assert_eq!(Dir::new(-2, 1), Pos::new(0, 4) - p);
+        assert_eq!(None, Pos::new(0, 0).checked_add(Dir::W));
+        assert_eq!(3, p.manhattan(Pos::new(1, 1)));
+        assert_eq!(Some(Dir::S), Dir::from_arrow('v'));
+        assert!(Pos::try_from(Dir::new(1, -1)).is_err());
+        assert_eq!(Ok(Dir::new(2, 3)), Dir::try_from(p));
+    }
+}
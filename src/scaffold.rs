@@ -0,0 +1,229 @@
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// Not a day of the calendar, which runs from 1 to 25.
+    NoSuchDay(usize),
+    Exists(PathBuf),
+    Registered(usize),
+    Malformed(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::NoSuchDay(day) => write!(f, "day {day} is not between 1 and 25"),
+            ScaffoldError::Exists(p) => write!(f, "{}: already exists", p.display()),
+            ScaffoldError::Registered(day) => write!(f, "day {day} is already registered"),
+            ScaffoldError::Malformed(p) => write!(f, "{}: cannot find the registry", p.display()),
+            ScaffoldError::Io(p, e) => write!(f, "{}: {}", p.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+static TEMPLATE: &str = "use crate::{
+    error::ParseError,
+    parse::{self, lines, unsigned},
+    Solution,
+};
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::finish(input, lines(unsigned))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[ignore = \"needs the example from the puzzle\"]
+    fn example() {
+        let input = DayN::parse(\"\").unwrap();
+
+        assert_eq!(0, DayN::part1(&input));
+        assert_eq!(0, DayN::part2(&input));
+    }
+}
+";
+
+/// The module for `day`, from the template.
+pub fn template(day: usize) -> String {
+    TEMPLATE.replace("DayN", &format!("Day{day}"))
+}
+
+/// `registry` with `day` declared and registered, in order.
+fn register(registry: &str, day: usize) -> Option<String> {
+    let name = format!("day{day}");
+    let entry = format!("    ({day}, parser::<day{day}::Day{day}>),");
+    // rustfmt orders modules by name, so `day1` < `day10` < `day2`
+    let module_name = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|l| l.strip_suffix(';'))
+            .map(str::to_string)
+    };
+    let entry_day = |line: &str| {
+        line.trim()
+            .strip_prefix('(')?
+            .split_once(',')?
+            .0
+            .parse::<usize>()
+            .ok()
+    };
+
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let mods = lines.iter().position(|l| module_name(l).is_some())?;
+    let at = lines[mods..]
+        .iter()
+        .position(|l| module_name(l).is_none_or(|m| m > name))
+        .map_or(lines.len(), |i| mods + i);
+    lines.insert(at, format!("pub mod {name};"));
+
+    let start = lines.iter().position(|l| l.contains("REGISTRY"))? + 1;
+    let end = start + lines[start..].iter().position(|l| l.trim() == "];")?;
+    let at = (start..end)
+        .find(|&i| entry_day(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    lines.insert(at, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds `src/days/dayN.rs` from the template, registers it and creates an
+/// empty `input/dayN.txt` under `root`, which `aoc verify` counts as missing
+/// until the input is pasted in. Nothing is written if any of it is already
+/// there, and files already written are removed again if a later write
+/// fails. Returns the files created.
+pub fn new_day(root: &Path, day: usize) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::NoSuchDay(day));
+    }
+    let module = root.join(format!("src/days/day{day}.rs"));
+    let input = root.join(format!("input/day{day}.txt"));
+    let registry = root.join("src/days/mod.rs");
+
+    for path in [&module, &input] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists(path.clone()));
+        }
+    }
+
+    let current =
+        std::fs::read_to_string(&registry).map_err(|e| ScaffoldError::Io(registry.clone(), e))?;
+    if current.contains(&format!("pub mod day{day};")) {
+        return Err(ScaffoldError::Registered(day));
+    }
+    let updated = register(&current, day).ok_or(ScaffoldError::Malformed(registry.clone()))?;
+
+    let mut written: Vec<&Path> = vec![];
+    for (path, content) in [
+        (&module, template(day)),
+        (&input, String::new()),
+        (&registry, updated),
+    ] {
+        if let Err(e) = std::fs::write(path, content) {
+            // so that trying again does not stop at `Exists`
+            for path in written {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(ScaffoldError::Io(path.clone(), e));
+        }
+        written.push(path);
+    }
+
+    Ok(vec![module, input])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registers_in_order() {
+        let registry = "use crate::solution::{parser, Parser};
+
+pub mod day1;
+pub mod day3;
+
+pub static REGISTRY: &[(usize, Parser)] = &[
+    (1, parser::<day1::Day1>),
+    (3, parser::<day3::Day3>),
+];
+";
+        let updated = register(registry, 2).unwrap();
+        assert!(updated.contains("pub mod day1;\npub mod day2;\npub mod day3;\n"));
+        assert!(updated.contains("Day1>),\n    (2, parser::<day2::Day2>),\n    (3,"));
+
+        let updated = register(registry, 20).unwrap();
+        assert!(updated.contains("pub mod day1;\npub mod day20;\npub mod day3;\n"));
+        assert!(updated.contains("Day3>),\n    (20, parser::<day20::Day20>),\n];"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root =
+            std::env::temp_dir().join(format!("aoc_2024_scaffold_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(
+            root.join("src/days/mod.rs"),
+            "pub mod day1;\n\npub static REGISTRY: &[(usize, Parser)] = &[\n    (1, parser::<day1::Day1>),\n];\n",
+        )
+        .unwrap();
+
+        // no input directory to write into
+        assert!(matches!(new_day(&root, 2), Err(ScaffoldError::Io(..))));
+        assert!(!root.join("src/days/day2.rs").exists());
+
+        std::fs::create_dir_all(root.join("input")).unwrap();
+        let created = new_day(&root, 2).unwrap();
+        assert_eq!(2, created.len());
+        assert!(std::fs::read_to_string(&created[0])
+            .unwrap()
+            .contains("impl Solution for Day2"));
+
+        assert!(matches!(new_day(&root, 2), Err(ScaffoldError::Exists(_))));
+        assert!(matches!(
+            new_day(&root, 1),
+            Err(ScaffoldError::Registered(1))
+        ));
+        for day in [0, 26] {
+            assert!(matches!(
+                new_day(&root, day),
+                Err(ScaffoldError::NoSuchDay(_))
+            ));
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
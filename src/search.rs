@@ -0,0 +1,296 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Distances and predecessors found by [`bfs`], [`dijkstra`] or [`astar`].
+///
+/// The search stops as soon as a goal state is settled, or when searching for
+/// all paths once nothing left can reach a goal as cheaply. `dist` holds exact
+/// distances for every settled state and tentative ones for the frontier.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    pub dist: HashMap<S, C>,
+    /// One predecessor per state, or every optimal one when searching for
+    /// all paths.
+    pub prev: HashMap<S, Vec<S>>,
+    pub goal: Option<S>,
+    /// Every goal state reached at the optimal cost, starting with `goal`.
+    /// Only more than one when searching for all paths.
+    pub goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            dist: HashMap::from([(start, zero)]),
+            prev: HashMap::new(),
+            goal: None,
+            goals: vec![],
+        }
+    }
+
+    /// Settles on `goal`, or adds it beside the first one.
+    fn reach(&mut self, goal: S) {
+        self.goal.get_or_insert_with(|| goal.clone());
+        self.goals.push(goal);
+    }
+
+    /// Records `cost` for `next` reached from `from`, returning whether it
+    /// improved on the known distance.
+    fn relax(&mut self, from: &S, next: S, cost: C, all_paths: bool) -> bool
+    where
+        C: Ord,
+    {
+        let ord = match self.dist.get(&next) {
+            Some(known) => cost.cmp(known),
+            None => Ordering::Less,
+        };
+
+        match ord {
+            Ordering::Less => {
+                self.dist.insert(next.clone(), cost);
+                self.prev.insert(next, vec![from.clone()]);
+                true
+            }
+            Ordering::Equal if all_paths => {
+                let prev = self.prev.entry(next).or_default();
+                if !prev.contains(from) {
+                    prev.push(from.clone());
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Distance to the goal that stopped the search.
+    pub fn cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|g| self.dist[g])
+    }
+
+    /// The path from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().map(|g| self.path_to(g))
+    }
+
+    /// Follows the first predecessor of every state back to the start.
+    pub fn path_to(&self, state: &S) -> Vec<S> {
+        let mut path = vec![state.clone()];
+
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev[0].clone());
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Every state on some optimal path to any of the goals. Only complete
+    /// when the search kept all paths.
+    pub fn optimal_states(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(s) = stack.pop() {
+            if let Some(prev) = self.prev.get(&s) {
+                stack.extend(prev.iter().filter(|p| !seen.contains(*p)).cloned());
+            }
+            seen.insert(s);
+        }
+
+        seen
+    }
+}
+
+/// Breadth-first search over unit-cost edges.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    all_paths: bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut open = VecDeque::from([start]);
+
+    while let Some(s) = open.pop_front() {
+        let cost = search.dist[&s];
+        if search.cost().is_some_and(|c| cost > c) {
+            break;
+        }
+
+        if goal(&s) {
+            search.reach(s);
+            if !all_paths {
+                break;
+            }
+            continue;
+        }
+
+        let cost = cost + 1;
+        for next in successors(&s) {
+            if search.relax(&s, next.clone(), cost, all_paths) {
+                open.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Shortest paths over non-negative edge costs.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    all_paths: bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), goal, all_paths)
+}
+
+/// [`dijkstra`] guided by a consistent `heuristic`, which must never
+/// overestimate the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+    all_paths: bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut open = BinaryHeap::from([Reverse(Entry(heuristic(&start), C::default(), start))]);
+    // once a goal is found, states with the same priority may still add
+    // equally short paths to it
+    let mut bound = None;
+
+    while let Some(Reverse(Entry(f, cost, s))) = open.pop() {
+        if bound.is_some_and(|b| f > b) {
+            break;
+        }
+
+        if cost > search.dist[&s] {
+            continue;
+        }
+
+        if goal(&s) {
+            if search.cost().is_none_or(|c| cost == c) {
+                search.reach(s);
+            }
+            if !all_paths {
+                break;
+            }
+            bound.get_or_insert(f);
+            continue;
+        }
+
+        for (next, step) in successors(&s) {
+            let cost = cost + step;
+            if search.relax(&s, next.clone(), cost, all_paths) {
+                open.push(Reverse(Entry(cost + heuristic(&next), cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+/// Heap entry ordered by priority, then cost, so states need not be `Ord`.
+struct Entry<S, C>(C, C, S);
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.0, &self.1).cmp(&(&other.0, &other.1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -1- 1 -1- 3
+    //  \-1- 2 -1-/
+    //   \----3--/
+    fn edges(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (3, 3)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn shortest() {
+        let search = dijkstra(0, edges, |&n| n == 3, false);
+
+        assert_eq!(Some(2), search.cost());
+        assert_eq!(3, search.path().unwrap().len());
+        assert_eq!(3, search.optimal_states().len());
+
+        let search = bfs(0, |n| edges(n).into_iter().map(|e| e.0), |&n| n == 3, false);
+        assert_eq!(Some(1), search.cost());
+        assert_eq!(Some(vec![0, 3]), search.path());
+    }
+
+    #[test]
+    fn all_optimal() {
+        let search = astar(0, edges, |&n| u32::from(n != 3), |&n| n == 3, true);
+
+        assert_eq!(Some(2), search.cost());
+        assert_eq!(HashSet::from([0, 1, 2, 3]), search.optimal_states());
+        assert_eq!(2, search.prev[&3].len());
+    }
+
+    #[test]
+    fn several_goals() {
+        // 0 -1- 1 -1- 3
+        //  \-1- 2 -1- 4
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(4, 1)],
+            _ => vec![],
+        };
+        let goal = |n: &u32| *n >= 3;
+
+        let search = dijkstra(0, edges, goal, true);
+        assert_eq!(Some(2), search.cost());
+        assert_eq!(2, search.goals.len());
+        assert_eq!(HashSet::from([0, 1, 2, 3, 4]), search.optimal_states());
+
+        let search = bfs(0, |n| edges(n).into_iter().map(|e| e.0), goal, true);
+        assert_eq!(HashSet::from([0, 1, 2, 3, 4]), search.optimal_states());
+
+        let search = dijkstra(0, edges, goal, false);
+        assert_eq!(1, search.goals.len());
+    }
+}
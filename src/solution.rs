@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use crate::{error::ParseError, Part};
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    type Input: 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Parsed input of some [`Solution`] with its answer types erased, so that
+/// days can be driven from the registry without knowing their types.
+pub trait DynSolution {
+    fn part1(&self) -> String;
+    fn part2(&self) -> String;
+
+    fn part(&self, part: Part) -> String {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> DynSolution for Parsed<S> {
+    fn part1(&self) -> String {
+        S::part1(&self.0).to_string()
+    }
+
+    fn part2(&self) -> String {
+        S::part2(&self.0).to_string()
+    }
+}
+
+pub type Parser = fn(&str) -> Result<Box<dyn DynSolution>, ParseError>;
+
+/// Type-erased [`Solution::parse`], the shape stored in the registry.
+pub fn parser<S: Solution + 'static>(input: &str) -> Result<Box<dyn DynSolution>, ParseError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
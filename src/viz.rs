@@ -0,0 +1,203 @@
+use std::fmt::Write;
+
+use crate::{
+    days::{day14, day15, day6},
+    error::ParseError,
+    grid::Grid,
+    image::{Image, Rgb, BLACK},
+    pos::Pos,
+};
+
+/// The eight basic terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    fn ansi(self) -> u8 {
+        30 + self as u8
+    }
+
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Black => BLACK,
+            Colour::Red => [220, 50, 50],
+            Colour::Green => [40, 200, 60],
+            Colour::Yellow => [250, 200, 40],
+            Colour::Blue => [60, 100, 230],
+            Colour::Magenta => [200, 60, 200],
+            Colour::Cyan => [40, 200, 210],
+            Colour::White => [240, 240, 240],
+        }
+    }
+}
+
+/// A character drawn over the grid, such as a guard or a robot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mark {
+    pub pos: Pos,
+    pub ch: char,
+    pub colour: Option<Colour>,
+}
+
+impl Mark {
+    pub fn new(pos: Pos, ch: char, colour: Option<Colour>) -> Self {
+        Self { pos, ch, colour }
+    }
+}
+
+/// One snapshot of a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    /// Colours for grid cells by character; marks bring their own.
+    pub palette: Vec<(char, Colour)>,
+    /// Drawn in order over the grid, so later marks win.
+    pub marks: Vec<Mark>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            palette: vec![],
+            marks: vec![],
+            caption: String::new(),
+        }
+    }
+
+    pub fn palette(mut self, palette: &[(char, Colour)]) -> Self {
+        self.palette = palette.to_vec();
+        self
+    }
+
+    pub fn marks(mut self, marks: impl IntoIterator<Item = Mark>) -> Self {
+        self.marks.extend(marks);
+        self
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// The grid as text with the marks drawn in, coloured with ANSI escapes
+    /// if `colour` is set. Marks off the grid are left out.
+    pub fn render(&self, colour: bool) -> String {
+        let mut cells = self
+            .grid
+            .map(|&c| (c, self.palette.iter().find(|p| p.0 == c).map(|p| p.1)));
+        for mark in self.marks.iter() {
+            if let Some(cell) = cells.get_mut(mark.pos) {
+                *cell = (mark.ch, mark.colour);
+            }
+        }
+
+        let mut out = String::new();
+        for row in cells.rows() {
+            for &(c, paint) in row {
+                match paint.filter(|_| colour) {
+                    Some(paint) => write!(out, "\x1b[{}m{c}\x1b[0m", paint.ansi()).unwrap(),
+                    None => out.push(c),
+                }
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// The frame as `scale` pixel squares. Cells without a colour are black
+    /// if they are `.` or blank and grey otherwise.
+    pub fn image(&self, scale: usize) -> Image {
+        let colour = |c: char| {
+            self.palette
+                .iter()
+                .find(|p| p.0 == c)
+                .map(|p| p.1.rgb())
+                .unwrap_or(if c == '.' || c == ' ' {
+                    BLACK
+                } else {
+                    [128, 128, 128]
+                })
+        };
+
+        let mut image = Image::from_grid(&self.grid, scale, |&c| colour(c));
+        for mark in self.marks.iter().filter(|m| self.grid.contains(m.pos)) {
+            let rgb = mark.colour.map_or_else(|| colour(mark.ch), Colour::rgb);
+            image.fill(mark.pos, scale, rgb);
+        }
+
+        image
+    }
+}
+
+/// A simulation that can be shown at any of its frames.
+pub trait Animation {
+    fn frames(&self) -> usize;
+
+    /// Panics if `i` is not below [`Animation::frames`].
+    fn frame(&mut self, i: usize) -> Frame;
+}
+
+pub type Animator = fn(&str) -> Result<Box<dyn Animation>, ParseError>;
+
+/// Days that can be watched, by day.
+pub static REGISTRY: &[(usize, Animator)] = &[
+    (6, day6::animate),
+    (14, day14::animate),
+    (15, day15::animate),
+];
+
+pub fn get(day: usize) -> Option<Animator> {
+    REGISTRY
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, animator)| animator)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen;
+
+    #[test]
+    fn render() {
+        let frame = Frame::new(Grid::parse("#.\n.#\n", |c| c).unwrap())
+            .palette(&[('#', Colour::Blue)])
+            .marks([
+                Mark::new(Pos::new(1, 0), '@', Some(Colour::Red)),
+                Mark::new(Pos::new(5, 5), '@', None),
+            ]);
+
+        assert_eq!("#.\n@#\n", frame.render(false));
+        assert_eq!(
+            "\x1b[34m#\x1b[0m.\n\x1b[31m@\x1b[0m\x1b[34m#\x1b[0m\n",
+            frame.render(true)
+        );
+    }
+
+    #[test]
+    fn every_animation_plays() {
+        for &(day, animator) in REGISTRY {
+            let input = gen::generate(day, 0, Some(10)).unwrap();
+            let mut animation = animator(&input).unwrap();
+            let n = animation.frames();
+            assert!(n > 0, "day {day} has no frames");
+
+            let first = animation.frame(0);
+            let last = animation.frame(n - 1);
+            assert_eq!(first.grid.nrow(), last.grid.nrow(), "day {day}");
+            assert_eq!(first, animation.frame(0), "day {day} seeks back");
+        }
+    }
+}